@@ -788,6 +788,7 @@ pub struct WorkspaceCommandEnvironment {
     immutable_heads_expression: Rc<UserRevsetExpression>,
     short_prefixes_expression: Option<Rc<UserRevsetExpression>>,
     conflict_marker_style: ConflictMarkerStyle,
+    term_width: usize,
 }
 
 impl WorkspaceCommandEnvironment {
@@ -810,6 +811,7 @@ impl WorkspaceCommandEnvironment {
             immutable_heads_expression: RevsetExpression::root(),
             short_prefixes_expression: None,
             conflict_marker_style: settings.get("ui.conflict-marker-style")?,
+            term_width: ui.term_width(),
         };
         env.immutable_heads_expression = env.load_immutable_heads_expression(ui)?;
         env.short_prefixes_expression = env.load_short_prefixes_expression(ui)?;
@@ -985,6 +987,7 @@ impl WorkspaceCommandEnvironment {
             id_prefix_context,
             self.immutable_expression(),
             self.conflict_marker_style,
+            self.term_width,
             &self.command.data.commit_template_extensions,
         )
     }
@@ -527,6 +527,7 @@ mod git {
     use jj_lib::git::UnexpectedGitBackendError;
 
     use super::*;
+    use crate::git_util;
 
     impl From<GitImportError> for CommandError {
         fn from(err: GitImportError) -> Self {
@@ -573,7 +574,7 @@ jj currently does not support partial clones. To use jj with this repository, tr
                     "Run `jj git remote rename` to give a different name.",
                 ),
                 GitFetchError::InvalidBranchPattern(_) => user_error(err),
-                GitFetchError::Subprocess(_) => user_error(err),
+                GitFetchError::Subprocess(_) => git_util::git_subprocess_user_error(err),
             }
         }
     }
@@ -586,7 +587,7 @@ jj currently does not support partial clones. To use jj with this repository, tr
                     err,
                     "Run `jj git remote rename` to give a different name.",
                 ),
-                GitPushError::Subprocess(_) => user_error(err),
+                GitPushError::Subprocess(_) => git_util::git_subprocess_user_error(err),
                 GitPushError::UnexpectedBackend(_) => user_error(err),
             }
         }
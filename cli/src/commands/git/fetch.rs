@@ -31,6 +31,7 @@ use crate::command_error::config_error;
 use crate::command_error::user_error;
 use crate::commands::git::get_single_remote;
 use crate::complete;
+use crate::git_util::print_git_fetch_summary;
 use crate::git_util::print_git_import_stats;
 use crate::git_util::with_remote_git_callbacks;
 use crate::ui::Ui;
@@ -180,6 +181,7 @@ fn do_git_fetch(
     }
     let import_stats = git_fetch.import_refs()?;
     print_git_import_stats(ui, tx.repo(), &import_stats, true)?;
+    print_git_fetch_summary(ui, &import_stats)?;
     warn_if_branches_not_found(ui, tx, branch_names, remotes)
 }
 
@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs::File;
+use std::io;
+use std::io::Write as _;
+use std::path::Path;
 use std::path::PathBuf;
 
+use itertools::Itertools as _;
+
 use crate::cli_util::CommandHelper;
 use crate::command_error::CommandError;
 use crate::ui::Ui;
@@ -25,6 +31,9 @@ pub struct UtilInstallManPagesArgs {
     /// `/usr/share/man`. The provided path will be appended with `man1`,
     /// etc., as appropriate
     path: PathBuf,
+    /// Don't add "SEE ALSO" sections referring to the sibling subcommands
+    #[arg(long)]
+    no_see_also: bool,
 }
 
 pub fn cmd_util_install_man_pages(
@@ -34,7 +43,61 @@ pub fn cmd_util_install_man_pages(
 ) -> Result<(), CommandError> {
     let man1_dir = args.path.join("man1");
     std::fs::create_dir_all(&man1_dir)?;
-    let app = command.app().clone();
-    clap_mangen::generate_to(app, man1_dir)?;
+    let mut app = command.app().clone().disable_help_subcommand(true);
+    app.build();
+    generate_man_pages(&app, &[], &man1_dir, !args.no_see_also)?;
     Ok(())
 }
+
+/// Writes manpages of the `cmd` and its subcommands to the `out_dir`.
+///
+/// This is similar to `clap_mangen::generate_to()`, but each page can refer to
+/// the pages of the `siblings` subcommands.
+fn generate_man_pages(
+    cmd: &clap::Command,
+    siblings: &[&str],
+    out_dir: &Path,
+    see_also: bool,
+) -> io::Result<()> {
+    let subcommands = cmd
+        .get_subcommands()
+        .filter(|subcmd| !subcmd.is_hide_set())
+        .collect_vec();
+    let subcommand_names = subcommands
+        .iter()
+        .map(|subcmd| page_title(subcmd))
+        .collect_vec();
+    for subcmd in subcommands {
+        generate_man_pages(subcmd, &subcommand_names, out_dir, see_also)?;
+    }
+
+    let page = clap_mangen::Man::new(cmd.clone());
+    let mut file = File::create(out_dir.join(page.get_filename()))?;
+    page.render(&mut file)?;
+    let title = page_title(cmd);
+    let see_also_titles = siblings
+        .iter()
+        .copied()
+        .filter(|&name| name != title)
+        .collect_vec();
+    if see_also && !see_also_titles.is_empty() {
+        write_see_also_section(&mut file, &see_also_titles)?;
+    }
+    file.flush()
+}
+
+/// Returns the manpage title, which is the command name qualified by the
+/// parent command names (e.g. `jj-git-fetch`).
+fn page_title(cmd: &clap::Command) -> &str {
+    cmd.get_display_name().unwrap_or_else(|| cmd.get_name())
+}
+
+/// Appends roff "SEE ALSO" section referring to the given section 1 pages.
+fn write_see_also_section(w: &mut dyn io::Write, titles: &[&str]) -> io::Result<()> {
+    writeln!(w, ".SH \"SEE ALSO\"")?;
+    let refs = titles
+        .iter()
+        .map(|title| format!(r"\fB{}\fR(1)", title.replace('-', r"\-")))
+        .join(", ");
+    writeln!(w, "{refs}")
+}
@@ -15,13 +15,18 @@
 use std::any::Any;
 use std::cmp::Ordering;
 use std::cmp::max;
+use std::cmp::min;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 use std::fmt::Display;
 use std::io;
 use std::rc::Rc;
+use std::slice;
 
 use bstr::BString;
+use bstr::ByteSlice as _;
 use futures::StreamExt as _;
 use futures::TryStreamExt as _;
 use futures::stream::BoxStream;
@@ -29,34 +34,44 @@ use itertools::Itertools as _;
 use jj_lib::backend::BackendResult;
 use jj_lib::backend::ChangeId;
 use jj_lib::backend::CommitId;
+use jj_lib::backend::Signature;
+use jj_lib::backend::Timestamp;
 use jj_lib::backend::TreeValue;
 use jj_lib::commit::Commit;
 use jj_lib::conflicts;
 use jj_lib::conflicts::ConflictMarkerStyle;
+use jj_lib::conflicts::MaterializedTreeValue;
 use jj_lib::copies::CopiesTreeDiffEntry;
 use jj_lib::copies::CopiesTreeDiffEntryPath;
 use jj_lib::copies::CopyRecords;
+use jj_lib::evolution::walk_predecessors;
 use jj_lib::extensions_map::ExtensionsMap;
+use jj_lib::files;
+use jj_lib::files::MergeResult;
 use jj_lib::fileset;
 use jj_lib::fileset::FilesetDiagnostics;
 use jj_lib::fileset::FilesetExpression;
 use jj_lib::id_prefix::IdPrefixContext;
 use jj_lib::id_prefix::IdPrefixIndex;
+use jj_lib::matchers::EverythingMatcher;
 use jj_lib::matchers::Matcher;
 use jj_lib::merge::MergedTreeValue;
 use jj_lib::merged_tree::MergedTree;
-use jj_lib::object_id::ObjectId as _;
+use jj_lib::object_id::ObjectId;
 use jj_lib::op_store::RefTarget;
 use jj_lib::op_store::RemoteRef;
 use jj_lib::ref_name::WorkspaceName;
 use jj_lib::ref_name::WorkspaceNameBuf;
 use jj_lib::repo::Repo;
+use jj_lib::repo_path::RepoPath;
 use jj_lib::repo_path::RepoPathBuf;
 use jj_lib::repo_path::RepoPathUiConverter;
 use jj_lib::revset;
 use jj_lib::revset::Revset;
 use jj_lib::revset::RevsetContainingFn;
 use jj_lib::revset::RevsetDiagnostics;
+use jj_lib::revset::RevsetExpression;
+use jj_lib::revset::RevsetIteratorExt as _;
 use jj_lib::revset::RevsetModifier;
 use jj_lib::revset::RevsetParseContext;
 use jj_lib::revset::UserRevsetExpression;
@@ -66,15 +81,23 @@ use jj_lib::signing::SignError;
 use jj_lib::signing::SignResult;
 use jj_lib::signing::Verification;
 use jj_lib::store::Store;
+use jj_lib::str_util::StringPattern;
 use jj_lib::trailer;
 use jj_lib::trailer::Trailer;
 use once_cell::unsync::OnceCell;
 use pollster::FutureExt as _;
 use serde::Serialize as _;
+use unicode_width::UnicodeWidthChar as _;
+use unicode_width::UnicodeWidthStr as _;
 
 use crate::diff_util;
+use crate::diff_util::DiffLines;
+use crate::diff_util::DiffStatBarChars;
+use crate::diff_util::DiffStatEntry;
 use crate::diff_util::DiffStats;
+use crate::formatter::FormatRecorder;
 use crate::formatter::Formatter;
+use crate::mailmap::Mailmap;
 use crate::revset_util;
 use crate::template_builder;
 use crate::template_builder::BuildContext;
@@ -95,6 +118,8 @@ use crate::templater;
 use crate::templater::BoxedSerializeProperty;
 use crate::templater::BoxedTemplateProperty;
 use crate::templater::ListTemplate;
+use crate::templater::Literal;
+use crate::templater::Pair;
 use crate::templater::PlainTextFormattedProperty;
 use crate::templater::SizeHint;
 use crate::templater::Template;
@@ -102,9 +127,19 @@ use crate::templater::TemplateFormatter;
 use crate::templater::TemplatePropertyError;
 use crate::templater::TemplatePropertyExt as _;
 
+/// Extension point for adding template functions and methods.
+///
+/// Methods registered by `build_fn_table()` can look up state populated by
+/// `build_cache_extensions()` through
+/// [`CommitTemplateLanguage::cache_extension()`]. This is useful for data that
+/// is expensive to compute or comes from an external source, such as issue
+/// statuses referenced by commit descriptions.
 pub trait CommitTemplateLanguageExtension {
+    /// Returns functions and methods to be merged into the builtin table.
     fn build_fn_table<'repo>(&self) -> CommitTemplateBuildFnTable<'repo>;
 
+    /// Registers per-language cache objects. They are instantiated once per
+    /// template language, and shared by all template evaluations.
     fn build_cache_extensions(&self, extensions: &mut ExtensionsMap);
 }
 
@@ -121,6 +156,7 @@ pub struct CommitTemplateLanguage<'repo> {
     id_prefix_context: &'repo IdPrefixContext,
     immutable_expression: Rc<UserRevsetExpression>,
     conflict_marker_style: ConflictMarkerStyle,
+    term_width: usize,
     build_fn_table: CommitTemplateBuildFnTable<'repo>,
     keyword_cache: CommitKeywordCache<'repo>,
     cache_extensions: ExtensionsMap,
@@ -138,6 +174,7 @@ impl<'repo> CommitTemplateLanguage<'repo> {
         id_prefix_context: &'repo IdPrefixContext,
         immutable_expression: Rc<UserRevsetExpression>,
         conflict_marker_style: ConflictMarkerStyle,
+        term_width: usize,
         extensions: &[impl AsRef<dyn CommitTemplateLanguageExtension>],
     ) -> Self {
         let mut build_fn_table = CommitTemplateBuildFnTable::builtin();
@@ -158,6 +195,7 @@ impl<'repo> CommitTemplateLanguage<'repo> {
             id_prefix_context,
             immutable_expression,
             conflict_marker_style,
+            term_width,
             build_fn_table,
             keyword_cache: CommitKeywordCache::default(),
             cache_extensions,
@@ -212,6 +250,21 @@ impl<'repo> TemplateLanguage<'repo> for CommitTemplateLanguage<'repo> {
                 let build = template_parser::lookup_method(type_name, table, function)?;
                 build(self, diagnostics, build_ctx, property, function)
             }
+            CommitTemplatePropertyKind::CommitListPair(property) => {
+                let table = &self.build_fn_table.commit_list_pair_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
+            CommitTemplatePropertyKind::CommitGroup(property) => {
+                let table = &self.build_fn_table.commit_group_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
+            CommitTemplatePropertyKind::CommitGroupList(property) => {
+                let table = &self.build_fn_table.commit_group_list_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
             CommitTemplatePropertyKind::CommitRef(property) => {
                 let table = &self.build_fn_table.commit_ref_methods;
                 let build = template_parser::lookup_method(type_name, table, function)?;
@@ -279,11 +332,23 @@ impl<'repo> TemplateLanguage<'repo> for CommitTemplateLanguage<'repo> {
                 let build = template_parser::lookup_method(type_name, table, function)?;
                 build(self, diagnostics, build_ctx, property, function)
             }
+            CommitTemplatePropertyKind::ChangeIdList(property) => {
+                let table = &self.build_fn_table.change_id_list_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
             CommitTemplatePropertyKind::CommitId(property) => {
                 let table = &self.build_fn_table.commit_id_methods;
                 let build = template_parser::lookup_method(type_name, table, function)?;
                 build(self, diagnostics, build_ctx, property, function)
             }
+            CommitTemplatePropertyKind::CommitIdOpt(property) => {
+                let type_name = "CommitId";
+                let table = &self.build_fn_table.commit_id_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                let inner_property = property.try_unwrap(type_name).into_dyn();
+                build(self, diagnostics, build_ctx, inner_property, function)
+            }
             CommitTemplatePropertyKind::ShortestIdPrefix(property) => {
                 let table = &self.build_fn_table.shortest_id_prefix_methods;
                 let build = template_parser::lookup_method(type_name, table, function)?;
@@ -322,6 +387,16 @@ impl<'repo> TemplateLanguage<'repo> for CommitTemplateLanguage<'repo> {
                 let property = property.map(|formatted| formatted.stats).into_dyn();
                 build(self, diagnostics, build_ctx, property, function)
             }
+            CommitTemplatePropertyKind::DiffStatEntry(property) => {
+                let table = &self.build_fn_table.diff_stat_entry_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
+            CommitTemplatePropertyKind::DiffStatEntryList(property) => {
+                let table = &self.build_fn_table.diff_stat_entry_list_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
             CommitTemplatePropertyKind::CryptographicSignatureOpt(property) => {
                 let type_name = "CryptographicSignature";
                 let table = &self.build_fn_table.cryptographic_signature_methods;
@@ -345,6 +420,16 @@ impl<'repo> TemplateLanguage<'repo> for CommitTemplateLanguage<'repo> {
                 let build = template_parser::lookup_method(type_name, table, function)?;
                 build(self, diagnostics, build_ctx, property, function)
             }
+            CommitTemplatePropertyKind::GitHeader(property) => {
+                let table = &self.build_fn_table.git_header_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
+            CommitTemplatePropertyKind::GitHeaderList(property) => {
+                let table = &self.build_fn_table.git_header_list_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(self, diagnostics, build_ctx, property, function)
+            }
         }
     }
 }
@@ -364,6 +449,7 @@ impl<'repo> CommitTemplateLanguage<'repo> {
         &self.keyword_cache
     }
 
+    /// Returns cache object registered by an extension, if any.
     pub fn cache_extension<T: Any>(&self) -> Option<&T> {
         self.cache_extensions.get::<T>()
     }
@@ -374,6 +460,9 @@ pub enum CommitTemplatePropertyKind<'repo> {
     Commit(BoxedTemplateProperty<'repo, Commit>),
     CommitOpt(BoxedTemplateProperty<'repo, Option<Commit>>),
     CommitList(BoxedTemplateProperty<'repo, Vec<Commit>>),
+    CommitListPair(BoxedTemplateProperty<'repo, Pair<Vec<Commit>, Vec<Commit>>>),
+    CommitGroup(BoxedTemplateProperty<'repo, Pair<String, Vec<Commit>>>),
+    CommitGroupList(BoxedTemplateProperty<'repo, Vec<Pair<String, Vec<Commit>>>>),
     CommitRef(BoxedTemplateProperty<'repo, Rc<CommitRef>>),
     CommitRefOpt(BoxedTemplateProperty<'repo, Option<Rc<CommitRef>>>),
     CommitRefList(BoxedTemplateProperty<'repo, Vec<Rc<CommitRef>>>),
@@ -385,7 +474,9 @@ pub enum CommitTemplatePropertyKind<'repo> {
     RepoPath(BoxedTemplateProperty<'repo, RepoPathBuf>),
     RepoPathOpt(BoxedTemplateProperty<'repo, Option<RepoPathBuf>>),
     ChangeId(BoxedTemplateProperty<'repo, ChangeId>),
+    ChangeIdList(BoxedTemplateProperty<'repo, Vec<ChangeId>>),
     CommitId(BoxedTemplateProperty<'repo, CommitId>),
+    CommitIdOpt(BoxedTemplateProperty<'repo, Option<CommitId>>),
     ShortestIdPrefix(BoxedTemplateProperty<'repo, ShortestIdPrefix>),
     TreeDiff(BoxedTemplateProperty<'repo, TreeDiff>),
     TreeDiffEntry(BoxedTemplateProperty<'repo, TreeDiffEntry>),
@@ -393,10 +484,14 @@ pub enum CommitTemplatePropertyKind<'repo> {
     TreeEntry(BoxedTemplateProperty<'repo, TreeEntry>),
     TreeEntryList(BoxedTemplateProperty<'repo, Vec<TreeEntry>>),
     DiffStats(BoxedTemplateProperty<'repo, DiffStatsFormatted<'repo>>),
+    DiffStatEntry(BoxedTemplateProperty<'repo, DiffStatEntry>),
+    DiffStatEntryList(BoxedTemplateProperty<'repo, Vec<DiffStatEntry>>),
     CryptographicSignatureOpt(BoxedTemplateProperty<'repo, Option<CryptographicSignature>>),
     AnnotationLine(BoxedTemplateProperty<'repo, AnnotationLine>),
     Trailer(BoxedTemplateProperty<'repo, Trailer>),
     TrailerList(BoxedTemplateProperty<'repo, Vec<Trailer>>),
+    GitHeader(BoxedTemplateProperty<'repo, GitHeader>),
+    GitHeaderList(BoxedTemplateProperty<'repo, Vec<GitHeader>>),
 }
 
 template_builder::impl_core_property_wrappers!(<'repo> CommitTemplatePropertyKind<'repo> => Core);
@@ -404,6 +499,9 @@ template_builder::impl_property_wrappers!(<'repo> CommitTemplatePropertyKind<'re
     Commit(Commit),
     CommitOpt(Option<Commit>),
     CommitList(Vec<Commit>),
+    CommitListPair(Pair<Vec<Commit>, Vec<Commit>>),
+    CommitGroup(Pair<String, Vec<Commit>>),
+    CommitGroupList(Vec<Pair<String, Vec<Commit>>>),
     CommitRef(Rc<CommitRef>),
     CommitRefOpt(Option<Rc<CommitRef>>),
     CommitRefList(Vec<Rc<CommitRef>>),
@@ -415,7 +513,9 @@ template_builder::impl_property_wrappers!(<'repo> CommitTemplatePropertyKind<'re
     RepoPath(RepoPathBuf),
     RepoPathOpt(Option<RepoPathBuf>),
     ChangeId(ChangeId),
+    ChangeIdList(Vec<ChangeId>),
     CommitId(CommitId),
+    CommitIdOpt(Option<CommitId>),
     ShortestIdPrefix(ShortestIdPrefix),
     TreeDiff(TreeDiff),
     TreeDiffEntry(TreeDiffEntry),
@@ -423,10 +523,14 @@ template_builder::impl_property_wrappers!(<'repo> CommitTemplatePropertyKind<'re
     TreeEntry(TreeEntry),
     TreeEntryList(Vec<TreeEntry>),
     DiffStats(DiffStatsFormatted<'repo>),
+    DiffStatEntry(DiffStatEntry),
+    DiffStatEntryList(Vec<DiffStatEntry>),
     CryptographicSignatureOpt(Option<CryptographicSignature>),
     AnnotationLine(AnnotationLine),
     Trailer(Trailer),
     TrailerList(Vec<Trailer>),
+    GitHeader(GitHeader),
+    GitHeaderList(Vec<GitHeader>),
 });
 
 impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo> {
@@ -444,6 +548,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::Commit(_) => "Commit",
             Self::CommitOpt(_) => "Option<Commit>",
             Self::CommitList(_) => "List<Commit>",
+            Self::CommitListPair(_) => "Pair<List<Commit>, List<Commit>>",
+            Self::CommitGroup(_) => "Pair<String, List<Commit>>",
+            Self::CommitGroupList(_) => "List<Pair<String, List<Commit>>>",
             Self::CommitRef(_) => "CommitRef",
             Self::CommitRefOpt(_) => "Option<CommitRef>",
             Self::CommitRefList(_) => "List<CommitRef>",
@@ -455,7 +562,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::RepoPath(_) => "RepoPath",
             Self::RepoPathOpt(_) => "Option<RepoPath>",
             Self::ChangeId(_) => "ChangeId",
+            Self::ChangeIdList(_) => "List<ChangeId>",
             Self::CommitId(_) => "CommitId",
+            Self::CommitIdOpt(_) => "Option<CommitId>",
             Self::ShortestIdPrefix(_) => "ShortestIdPrefix",
             Self::TreeDiff(_) => "TreeDiff",
             Self::TreeDiffEntry(_) => "TreeDiffEntry",
@@ -463,10 +572,14 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::TreeEntry(_) => "TreeEntry",
             Self::TreeEntryList(_) => "List<TreeEntry>",
             Self::DiffStats(_) => "DiffStats",
+            Self::DiffStatEntry(_) => "DiffStatEntry",
+            Self::DiffStatEntryList(_) => "List<DiffStatEntry>",
             Self::CryptographicSignatureOpt(_) => "Option<CryptographicSignature>",
             Self::AnnotationLine(_) => "AnnotationLine",
             Self::Trailer(_) => "Trailer",
             Self::TrailerList(_) => "List<Trailer>",
+            Self::GitHeader(_) => "GitHeader",
+            Self::GitHeaderList(_) => "List<GitHeader>",
         }
     }
 
@@ -476,6 +589,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::Commit(_) => None,
             Self::CommitOpt(property) => Some(property.map(|opt| opt.is_some()).into_dyn()),
             Self::CommitList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
+            Self::CommitListPair(_) => None,
+            Self::CommitGroup(_) => None,
+            Self::CommitGroupList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
             Self::CommitRef(_) => None,
             Self::CommitRefOpt(property) => Some(property.map(|opt| opt.is_some()).into_dyn()),
             Self::CommitRefList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
@@ -487,22 +603,27 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::RepoPath(_) => None,
             Self::RepoPathOpt(property) => Some(property.map(|opt| opt.is_some()).into_dyn()),
             Self::ChangeId(_) => None,
+            Self::ChangeIdList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
             Self::CommitId(_) => None,
+            Self::CommitIdOpt(property) => Some(property.map(|opt| opt.is_some()).into_dyn()),
             Self::ShortestIdPrefix(_) => None,
-            // TODO: boolean cast could be implemented, but explicit
-            // diff.empty() method might be better.
+            // Use explicit diff.empty() method instead.
             Self::TreeDiff(_) => None,
             Self::TreeDiffEntry(_) => None,
             Self::TreeDiffEntryList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
             Self::TreeEntry(_) => None,
             Self::TreeEntryList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
             Self::DiffStats(_) => None,
+            Self::DiffStatEntry(_) => None,
+            Self::DiffStatEntryList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
             Self::CryptographicSignatureOpt(property) => {
                 Some(property.map(|sig| sig.is_some()).into_dyn())
             }
             Self::AnnotationLine(_) => None,
             Self::Trailer(_) => None,
             Self::TrailerList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
+            Self::GitHeader(_) => None,
+            Self::GitHeaderList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
         }
     }
 
@@ -513,6 +634,20 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
         }
     }
 
+    fn try_into_timestamp(self) -> Option<BoxedTemplateProperty<'repo, Timestamp>> {
+        match self {
+            Self::Core(property) => property.try_into_timestamp(),
+            _ => None,
+        }
+    }
+
+    fn try_into_string_list(self) -> Option<BoxedTemplateProperty<'repo, Vec<String>>> {
+        match self {
+            Self::Core(property) => property.try_into_string_list(),
+            _ => None,
+        }
+    }
+
     fn try_into_stringify(self) -> Option<BoxedTemplateProperty<'repo, String>> {
         match self {
             Self::Core(property) => property.try_into_stringify(),
@@ -536,6 +671,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::Commit(property) => Some(property.into_serialize()),
             Self::CommitOpt(property) => Some(property.into_serialize()),
             Self::CommitList(property) => Some(property.into_serialize()),
+            Self::CommitListPair(_) => None,
+            Self::CommitGroup(_) => None,
+            Self::CommitGroupList(_) => None,
             Self::CommitRef(property) => Some(property.into_serialize()),
             Self::CommitRefOpt(property) => Some(property.into_serialize()),
             Self::CommitRefList(property) => Some(property.into_serialize()),
@@ -547,7 +685,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::RepoPath(property) => Some(property.into_serialize()),
             Self::RepoPathOpt(property) => Some(property.into_serialize()),
             Self::ChangeId(property) => Some(property.into_serialize()),
+            Self::ChangeIdList(property) => Some(property.into_serialize()),
             Self::CommitId(property) => Some(property.into_serialize()),
+            Self::CommitIdOpt(property) => Some(property.into_serialize()),
             Self::ShortestIdPrefix(property) => Some(property.into_serialize()),
             Self::TreeDiff(_) => None,
             Self::TreeDiffEntry(_) => None,
@@ -555,10 +695,14 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::TreeEntry(_) => None,
             Self::TreeEntryList(_) => None,
             Self::DiffStats(_) => None,
+            Self::DiffStatEntry(_) => None,
+            Self::DiffStatEntryList(_) => None,
             Self::CryptographicSignatureOpt(_) => None,
             Self::AnnotationLine(_) => None,
             Self::Trailer(_) => None,
             Self::TrailerList(_) => None,
+            Self::GitHeader(_) => None,
+            Self::GitHeaderList(_) => None,
         }
     }
 
@@ -568,6 +712,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::Commit(_) => None,
             Self::CommitOpt(_) => None,
             Self::CommitList(_) => None,
+            Self::CommitListPair(_) => None,
+            Self::CommitGroup(_) => None,
+            Self::CommitGroupList(_) => None,
             Self::CommitRef(property) => Some(property.into_template()),
             Self::CommitRefOpt(property) => Some(property.into_template()),
             Self::CommitRefList(property) => Some(property.into_template()),
@@ -579,7 +726,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::RepoPath(property) => Some(property.into_template()),
             Self::RepoPathOpt(property) => Some(property.into_template()),
             Self::ChangeId(property) => Some(property.into_template()),
+            Self::ChangeIdList(property) => Some(property.into_template()),
             Self::CommitId(property) => Some(property.into_template()),
+            Self::CommitIdOpt(property) => Some(property.into_template()),
             Self::ShortestIdPrefix(property) => Some(property.into_template()),
             Self::TreeDiff(_) => None,
             Self::TreeDiffEntry(_) => None,
@@ -587,10 +736,14 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             Self::TreeEntry(_) => None,
             Self::TreeEntryList(_) => None,
             Self::DiffStats(property) => Some(property.into_template()),
+            Self::DiffStatEntry(_) => None,
+            Self::DiffStatEntryList(_) => None,
             Self::CryptographicSignatureOpt(_) => None,
             Self::AnnotationLine(_) => None,
             Self::Trailer(property) => Some(property.into_template()),
             Self::TrailerList(property) => Some(property.into_template()),
+            Self::GitHeader(property) => Some(property.into_template()),
+            Self::GitHeaderList(property) => Some(property.into_template()),
         }
     }
 
@@ -626,10 +779,28 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             (Self::RefSymbolOpt(lhs), Self::RefSymbolOpt(rhs)) => {
                 Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
             }
+            (Self::ChangeId(lhs), Self::ChangeId(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
+            }
+            (Self::CommitId(lhs), Self::CommitId(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
+            }
+            (Self::CommitId(lhs), Self::CommitIdOpt(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| Some(l) == r).into_dyn())
+            }
+            (Self::CommitIdOpt(lhs), Self::CommitId(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l == Some(r)).into_dyn())
+            }
+            (Self::CommitIdOpt(lhs), Self::CommitIdOpt(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
+            }
             (Self::Core(_), _) => None,
             (Self::Commit(_), _) => None,
             (Self::CommitOpt(_), _) => None,
             (Self::CommitList(_), _) => None,
+            (Self::CommitListPair(_), _) => None,
+            (Self::CommitGroup(_), _) => None,
+            (Self::CommitGroupList(_), _) => None,
             (Self::CommitRef(_), _) => None,
             (Self::CommitRefOpt(_), _) => None,
             (Self::CommitRefList(_), _) => None,
@@ -641,7 +812,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             (Self::RepoPath(_), _) => None,
             (Self::RepoPathOpt(_), _) => None,
             (Self::ChangeId(_), _) => None,
+            (Self::ChangeIdList(_), _) => None,
             (Self::CommitId(_), _) => None,
+            (Self::CommitIdOpt(_), _) => None,
             (Self::ShortestIdPrefix(_), _) => None,
             (Self::TreeDiff(_), _) => None,
             (Self::TreeDiffEntry(_), _) => None,
@@ -649,20 +822,33 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             (Self::TreeEntry(_), _) => None,
             (Self::TreeEntryList(_), _) => None,
             (Self::DiffStats(_), _) => None,
+            (Self::DiffStatEntry(_), _) => None,
+            (Self::DiffStatEntryList(_), _) => None,
             (Self::CryptographicSignatureOpt(_), _) => None,
             (Self::AnnotationLine(_), _) => None,
             (Self::Trailer(_), _) => None,
             (Self::TrailerList(_), _) => None,
+            (Self::GitHeader(_), _) => None,
+            (Self::GitHeaderList(_), _) => None,
         }
     }
 
     fn try_into_cmp(self, other: Self) -> Option<BoxedTemplateProperty<'repo, Ordering>> {
         match (self, other) {
             (Self::Core(lhs), Self::Core(rhs)) => lhs.try_into_cmp(rhs),
+            (Self::ChangeId(lhs), Self::ChangeId(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l.cmp(&r)).into_dyn())
+            }
+            (Self::CommitId(lhs), Self::CommitId(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l.cmp(&r)).into_dyn())
+            }
             (Self::Core(_), _) => None,
             (Self::Commit(_), _) => None,
             (Self::CommitOpt(_), _) => None,
             (Self::CommitList(_), _) => None,
+            (Self::CommitListPair(_), _) => None,
+            (Self::CommitGroup(_), _) => None,
+            (Self::CommitGroupList(_), _) => None,
             (Self::CommitRef(_), _) => None,
             (Self::CommitRefOpt(_), _) => None,
             (Self::CommitRefList(_), _) => None,
@@ -674,7 +860,9 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             (Self::RepoPath(_), _) => None,
             (Self::RepoPathOpt(_), _) => None,
             (Self::ChangeId(_), _) => None,
+            (Self::ChangeIdList(_), _) => None,
             (Self::CommitId(_), _) => None,
+            (Self::CommitIdOpt(_), _) => None,
             (Self::ShortestIdPrefix(_), _) => None,
             (Self::TreeDiff(_), _) => None,
             (Self::TreeDiffEntry(_), _) => None,
@@ -682,10 +870,14 @@ impl<'repo> CoreTemplatePropertyVar<'repo> for CommitTemplatePropertyKind<'repo>
             (Self::TreeEntry(_), _) => None,
             (Self::TreeEntryList(_), _) => None,
             (Self::DiffStats(_), _) => None,
+            (Self::DiffStatEntry(_), _) => None,
+            (Self::DiffStatEntryList(_), _) => None,
             (Self::CryptographicSignatureOpt(_), _) => None,
             (Self::AnnotationLine(_), _) => None,
             (Self::Trailer(_), _) => None,
             (Self::TrailerList(_), _) => None,
+            (Self::GitHeader(_), _) => None,
+            (Self::GitHeaderList(_), _) => None,
         }
     }
 }
@@ -699,12 +891,18 @@ pub struct CommitTemplateBuildFnTable<'repo> {
     pub core: CoreTemplateBuildFnTable<'repo, CommitTemplateLanguage<'repo>>,
     pub commit_methods: CommitTemplateBuildMethodFnMap<'repo, Commit>,
     pub commit_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<Commit>>,
+    pub commit_list_pair_methods:
+        CommitTemplateBuildMethodFnMap<'repo, Pair<Vec<Commit>, Vec<Commit>>>,
+    pub commit_group_methods: CommitTemplateBuildMethodFnMap<'repo, Pair<String, Vec<Commit>>>,
+    pub commit_group_list_methods:
+        CommitTemplateBuildMethodFnMap<'repo, Vec<Pair<String, Vec<Commit>>>>,
     pub commit_ref_methods: CommitTemplateBuildMethodFnMap<'repo, Rc<CommitRef>>,
     pub commit_ref_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<Rc<CommitRef>>>,
     pub workspace_ref_methods: CommitTemplateBuildMethodFnMap<'repo, WorkspaceRef>,
     pub workspace_ref_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<WorkspaceRef>>,
     pub repo_path_methods: CommitTemplateBuildMethodFnMap<'repo, RepoPathBuf>,
     pub change_id_methods: CommitTemplateBuildMethodFnMap<'repo, ChangeId>,
+    pub change_id_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<ChangeId>>,
     pub commit_id_methods: CommitTemplateBuildMethodFnMap<'repo, CommitId>,
     pub shortest_id_prefix_methods: CommitTemplateBuildMethodFnMap<'repo, ShortestIdPrefix>,
     pub tree_diff_methods: CommitTemplateBuildMethodFnMap<'repo, TreeDiff>,
@@ -713,26 +911,36 @@ pub struct CommitTemplateBuildFnTable<'repo> {
     pub tree_entry_methods: CommitTemplateBuildMethodFnMap<'repo, TreeEntry>,
     pub tree_entry_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<TreeEntry>>,
     pub diff_stats_methods: CommitTemplateBuildMethodFnMap<'repo, DiffStats>,
+    pub diff_stat_entry_methods: CommitTemplateBuildMethodFnMap<'repo, DiffStatEntry>,
+    pub diff_stat_entry_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<DiffStatEntry>>,
     pub cryptographic_signature_methods:
         CommitTemplateBuildMethodFnMap<'repo, CryptographicSignature>,
     pub annotation_line_methods: CommitTemplateBuildMethodFnMap<'repo, AnnotationLine>,
     pub trailer_methods: CommitTemplateBuildMethodFnMap<'repo, Trailer>,
     pub trailer_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<Trailer>>,
+    pub git_header_methods: CommitTemplateBuildMethodFnMap<'repo, GitHeader>,
+    pub git_header_list_methods: CommitTemplateBuildMethodFnMap<'repo, Vec<GitHeader>>,
 }
 
 impl<'repo> CommitTemplateBuildFnTable<'repo> {
     /// Creates new symbol table containing the builtin methods.
     fn builtin() -> Self {
+        let mut core = CoreTemplateBuildFnTable::builtin();
+        merge_fn_map(&mut core.signature_methods, builtin_signature_methods());
         Self {
-            core: CoreTemplateBuildFnTable::builtin(),
+            core,
             commit_methods: builtin_commit_methods(),
-            commit_list_methods: template_builder::builtin_unformattable_list_methods(),
+            commit_list_methods: builtin_commit_list_methods(),
+            commit_list_pair_methods: template_builder::builtin_partition_methods(),
+            commit_group_methods: template_builder::builtin_group_methods(),
+            commit_group_list_methods: template_builder::builtin_unformattable_list_methods(),
             commit_ref_methods: builtin_commit_ref_methods(),
-            commit_ref_list_methods: template_builder::builtin_formattable_list_methods(),
+            commit_ref_list_methods: builtin_commit_ref_list_methods(),
             workspace_ref_methods: builtin_workspace_ref_methods(),
-            workspace_ref_list_methods: template_builder::builtin_formattable_list_methods(),
+            workspace_ref_list_methods: builtin_workspace_ref_list_methods(),
             repo_path_methods: builtin_repo_path_methods(),
             change_id_methods: builtin_change_id_methods(),
+            change_id_list_methods: template_builder::builtin_formattable_list_methods(),
             commit_id_methods: builtin_commit_id_methods(),
             shortest_id_prefix_methods: builtin_shortest_id_prefix_methods(),
             tree_diff_methods: builtin_tree_diff_methods(),
@@ -741,10 +949,14 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
             tree_entry_methods: builtin_tree_entry_methods(),
             tree_entry_list_methods: template_builder::builtin_unformattable_list_methods(),
             diff_stats_methods: builtin_diff_stats_methods(),
+            diff_stat_entry_methods: builtin_diff_stat_entry_methods(),
+            diff_stat_entry_list_methods: template_builder::builtin_unformattable_list_methods(),
             cryptographic_signature_methods: builtin_cryptographic_signature_methods(),
             annotation_line_methods: builtin_annotation_line_methods(),
             trailer_methods: builtin_trailer_methods(),
             trailer_list_methods: builtin_trailer_list_methods(),
+            git_header_methods: builtin_git_header_methods(),
+            git_header_list_methods: template_builder::builtin_formattable_list_methods(),
         }
     }
 
@@ -753,12 +965,16 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
             core: CoreTemplateBuildFnTable::empty(),
             commit_methods: HashMap::new(),
             commit_list_methods: HashMap::new(),
+            commit_list_pair_methods: HashMap::new(),
+            commit_group_methods: HashMap::new(),
+            commit_group_list_methods: HashMap::new(),
             commit_ref_methods: HashMap::new(),
             commit_ref_list_methods: HashMap::new(),
             workspace_ref_methods: HashMap::new(),
             workspace_ref_list_methods: HashMap::new(),
             repo_path_methods: HashMap::new(),
             change_id_methods: HashMap::new(),
+            change_id_list_methods: HashMap::new(),
             commit_id_methods: HashMap::new(),
             shortest_id_prefix_methods: HashMap::new(),
             tree_diff_methods: HashMap::new(),
@@ -767,10 +983,14 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
             tree_entry_methods: HashMap::new(),
             tree_entry_list_methods: HashMap::new(),
             diff_stats_methods: HashMap::new(),
+            diff_stat_entry_methods: HashMap::new(),
+            diff_stat_entry_list_methods: HashMap::new(),
             cryptographic_signature_methods: HashMap::new(),
             annotation_line_methods: HashMap::new(),
             trailer_methods: HashMap::new(),
             trailer_list_methods: HashMap::new(),
+            git_header_methods: HashMap::new(),
+            git_header_list_methods: HashMap::new(),
         }
     }
 
@@ -779,12 +999,16 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
             core,
             commit_methods,
             commit_list_methods,
+            commit_list_pair_methods,
+            commit_group_methods,
+            commit_group_list_methods,
             commit_ref_methods,
             commit_ref_list_methods,
             workspace_ref_methods,
             workspace_ref_list_methods,
             repo_path_methods,
             change_id_methods,
+            change_id_list_methods,
             commit_id_methods,
             shortest_id_prefix_methods,
             tree_diff_methods,
@@ -793,15 +1017,25 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
             tree_entry_methods,
             tree_entry_list_methods,
             diff_stats_methods,
+            diff_stat_entry_methods,
+            diff_stat_entry_list_methods,
             cryptographic_signature_methods,
             annotation_line_methods,
             trailer_methods,
             trailer_list_methods,
+            git_header_methods,
+            git_header_list_methods,
         } = extension;
 
         self.core.merge(core);
         merge_fn_map(&mut self.commit_methods, commit_methods);
         merge_fn_map(&mut self.commit_list_methods, commit_list_methods);
+        merge_fn_map(&mut self.commit_list_pair_methods, commit_list_pair_methods);
+        merge_fn_map(&mut self.commit_group_methods, commit_group_methods);
+        merge_fn_map(
+            &mut self.commit_group_list_methods,
+            commit_group_list_methods,
+        );
         merge_fn_map(&mut self.commit_ref_methods, commit_ref_methods);
         merge_fn_map(&mut self.commit_ref_list_methods, commit_ref_list_methods);
         merge_fn_map(&mut self.workspace_ref_methods, workspace_ref_methods);
@@ -811,6 +1045,7 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
         );
         merge_fn_map(&mut self.repo_path_methods, repo_path_methods);
         merge_fn_map(&mut self.change_id_methods, change_id_methods);
+        merge_fn_map(&mut self.change_id_list_methods, change_id_list_methods);
         merge_fn_map(&mut self.commit_id_methods, commit_id_methods);
         merge_fn_map(
             &mut self.shortest_id_prefix_methods,
@@ -825,6 +1060,11 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
         merge_fn_map(&mut self.tree_entry_methods, tree_entry_methods);
         merge_fn_map(&mut self.tree_entry_list_methods, tree_entry_list_methods);
         merge_fn_map(&mut self.diff_stats_methods, diff_stats_methods);
+        merge_fn_map(&mut self.diff_stat_entry_methods, diff_stat_entry_methods);
+        merge_fn_map(
+            &mut self.diff_stat_entry_list_methods,
+            diff_stat_entry_list_methods,
+        );
         merge_fn_map(
             &mut self.cryptographic_signature_methods,
             cryptographic_signature_methods,
@@ -832,6 +1072,8 @@ impl<'repo> CommitTemplateBuildFnTable<'repo> {
         merge_fn_map(&mut self.annotation_line_methods, annotation_line_methods);
         merge_fn_map(&mut self.trailer_methods, trailer_methods);
         merge_fn_map(&mut self.trailer_list_methods, trailer_list_methods);
+        merge_fn_map(&mut self.git_header_methods, git_header_methods);
+        merge_fn_map(&mut self.git_header_list_methods, git_header_list_methods);
     }
 }
 
@@ -842,6 +1084,8 @@ pub struct CommitKeywordCache<'repo> {
     tags_index: OnceCell<Rc<CommitRefsIndex>>,
     git_refs_index: OnceCell<Rc<CommitRefsIndex>>,
     is_immutable_fn: OnceCell<Rc<RevsetContainingFn<'repo>>>,
+    mailmap: OnceCell<Rc<Mailmap>>,
+    git_remote_urls: OnceCell<Rc<HashMap<String, String>>>,
 }
 
 impl<'repo> CommitKeywordCache<'repo> {
@@ -874,267 +1118,842 @@ impl<'repo> CommitKeywordCache<'repo> {
             Ok(revset.containing_fn().into())
         })
     }
+
+    pub fn mailmap(
+        &self,
+        repo: &dyn Repo,
+        workspace_name: &WorkspaceName,
+    ) -> BackendResult<&Rc<Mailmap>> {
+        self.mailmap
+            .get_or_try_init(|| Ok(Rc::new(load_mailmap(repo, workspace_name)?)))
+    }
+
+    pub fn git_remote_urls(
+        &self,
+        store: &Store,
+    ) -> Result<&Rc<HashMap<String, String>>, Box<dyn std::error::Error + Send + Sync>> {
+        self.git_remote_urls
+            .get_or_try_init(|| Ok(Rc::new(load_git_remote_urls(store)?)))
+    }
 }
 
-fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Commit> {
+/// Loads `.mailmap` file from the root directory of the working-copy commit.
+fn load_mailmap(repo: &dyn Repo, workspace_name: &WorkspaceName) -> BackendResult<Mailmap> {
+    let Some(commit_id) = repo.view().get_wc_commit_id(workspace_name) else {
+        return Ok(Mailmap::default());
+    };
+    let commit = repo.store().get_commit(commit_id)?;
+    let path = RepoPath::from_internal_string(".mailmap").unwrap();
+    let value = commit.tree()?.path_value(path)?;
+    let Some(Some(TreeValue::File { id, .. })) = value.as_resolved() else {
+        return Ok(Mailmap::default());
+    };
+    let content = diff_util::read_file_prefix(repo.store(), path, id, usize::MAX).block_on()?;
+    Ok(Mailmap::parse(&String::from_utf8_lossy(&content)))
+}
+
+fn builtin_signature_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Signature> {
     // Not using maplit::hashmap!{} or custom declarative macro here because
     // code completion inside macro is quite restricted.
-    let mut map = CommitTemplateBuildMethodFnMap::<Commit>::new();
+    let mut map = CommitTemplateBuildMethodFnMap::<Signature>::new();
     map.insert(
-        "description",
-        |_language, _diagnostics, _build_ctx, self_property, function| {
+        "canonical",
+        |language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.map(|commit| commit.description().to_owned());
+            let mailmap = language
+                .keyword_cache
+                .mailmap(language.repo, &language.workspace_name)
+                .map_err(|err| {
+                    TemplateParseError::expression("Failed to load .mailmap", function.name_span)
+                        .with_source(err)
+                })?
+                .clone();
+            let out_property = self_property.map(move |signature| mailmap.resolve(&signature));
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map
+}
+
+fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Commit> {
+    // Not using maplit::hashmap!{} or custom declarative macro here because
+    // code completion inside macro is quite restricted.
+    let mut map = CommitTemplateBuildMethodFnMap::<Commit>::new();
     map.insert(
-        "trailers",
+        "description",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property
-                .map(|commit| trailer::parse_description_trailers(commit.description()));
+            let out_property = self_property.map(|commit| commit.description().to_owned());
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "change_id",
+        "subject_is_imperative",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.map(|commit| commit.change_id().to_owned());
+            let out_property =
+                self_property.map(|commit| is_imperative_subject(commit.description()));
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "commit_id",
+        "subject_ends_with_period",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.map(|commit| commit.id().to_owned());
+            let out_property =
+                self_property.map(|commit| first_line(commit.description()).ends_with('.'));
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "parents",
+        "is_fixup",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.and_then(|commit| {
-                let commits: Vec<_> = commit.parents().try_collect()?;
-                Ok(commits)
-            });
+            let out_property = self_property
+                .map(|commit| parse_fixup_target_subject(commit.description()).is_some());
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "author",
+        "fixup_target_subject",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.map(|commit| commit.author().clone());
+            let out_property = self_property.map(|commit| {
+                parse_fixup_target_subject(commit.description())
+                    .unwrap_or_default()
+                    .to_owned()
+            });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "committer",
-        |_language, _diagnostics, _build_ctx, self_property, function| {
-            function.expect_no_arguments()?;
-            let out_property = self_property.map(|commit| commit.committer().clone());
+        "first_line_over_limit",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [limit_node] = function.expect_exact_arguments()?;
+            let limit_property = template_builder::expect_usize_expression(
+                language,
+                diagnostics,
+                build_ctx,
+                limit_node,
+            )?;
+            let out_property = (self_property, limit_property)
+                .map(|(commit, limit)| first_line(commit.description()).width() > limit);
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "mine",
+        "reverts",
         |language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let user_email = language.revset_parse_context.user_email.to_owned();
-            let out_property = self_property.map(move |commit| commit.author().email == user_email);
+            let commit_id_length = language.repo.store().commit_id_length();
+            let out_property = self_property.map(move |commit| {
+                parse_reverted_commit_id(commit.description(), commit_id_length)
+            });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "signature",
+        "subject_matches_parent",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.map(CryptographicSignature::new);
-            Ok(out_property.into_dyn_wrapped())
-        },
-    );
-    map.insert(
-        "working_copies",
-        |language, _diagnostics, _build_ctx, self_property, function| {
-            function.expect_no_arguments()?;
-            let repo = language.repo;
-            let out_property = self_property.map(|commit| extract_working_copies(repo, &commit));
+            // Only the immediate parents are loaded, not further ancestors.
+            let out_property = self_property.and_then(|commit| {
+                let subject = first_line(commit.description());
+                if subject.is_empty() {
+                    return Ok(false);
+                }
+                for parent in commit.parents() {
+                    if first_line(parent?.description()) == subject {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "current_working_copy",
-        |language, _diagnostics, _build_ctx, self_property, function| {
+        "trailers",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let repo = language.repo;
-            let name = language.workspace_name.clone();
             let out_property = self_property
-                .map(move |commit| Some(commit.id()) == repo.view().get_wc_commit_id(&name));
+                .map(|commit| trailer::parse_description_trailers(commit.description()));
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "bookmarks",
-        |language, _diagnostics, _build_ctx, self_property, function| {
-            function.expect_no_arguments()?;
-            let index = language
-                .keyword_cache
-                .bookmarks_index(language.repo)
-                .clone();
+        "trailer",
+        |_language, diagnostics, _build_ctx, self_property, function| {
+            let [key_node] = function.expect_exact_arguments()?;
+            let key =
+                template_parser::catch_aliases(diagnostics, key_node, |_diagnostics, node| {
+                    Ok(template_parser::expect_string_literal(node)?.to_owned())
+                })?;
             let out_property = self_property.map(move |commit| {
-                index
-                    .get(commit.id())
-                    .iter()
-                    .filter(|commit_ref| commit_ref.is_local() || !commit_ref.synced)
-                    .cloned()
-                    .collect_vec()
+                trailer::parse_description_trailers(commit.description())
+                    .into_iter()
+                    .rev()
+                    .find(|trailer| trailer.key.eq_ignore_ascii_case(&key))
+                    .map(|trailer| trailer.value)
             });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "local_bookmarks",
-        |language, _diagnostics, _build_ctx, self_property, function| {
+        "signed_off_by_count",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let index = language
-                .keyword_cache
-                .bookmarks_index(language.repo)
-                .clone();
-            let out_property = self_property.map(move |commit| {
-                index
-                    .get(commit.id())
+            let out_property = self_property.and_then(|commit| {
+                let trailers = trailer::parse_description_trailers(commit.description());
+                let emails: HashSet<_> = trailers
                     .iter()
-                    .filter(|commit_ref| commit_ref.is_local())
-                    .cloned()
-                    .collect_vec()
+                    .filter(|trailer| trailer.key == "Signed-off-by")
+                    .map(|trailer| signed_off_by_email(&trailer.value))
+                    .collect();
+                Ok(i64::try_from(emails.len())?)
             });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "remote_bookmarks",
+        "git_headers",
         |language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let index = language
-                .keyword_cache
-                .bookmarks_index(language.repo)
-                .clone();
-            let out_property = self_property.map(move |commit| {
-                index
-                    .get(commit.id())
-                    .iter()
-                    .filter(|commit_ref| commit_ref.is_remote())
-                    .cloned()
-                    .collect_vec()
-            });
+            let store = language.repo.store().clone();
+            let out_property =
+                self_property.and_then(move |commit| read_git_headers(&store, &commit));
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "tags",
+        "raw_git_object",
         |language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let index = language.keyword_cache.tags_index(language.repo).clone();
-            let out_property = self_property.map(move |commit| index.get(commit.id()).to_vec());
+            let store = language.repo.store().clone();
+            let out_property = self_property.and_then(move |commit| {
+                let data = read_git_commit_object(&store, &commit)?;
+                Ok(BString::from(data.unwrap_or_default()))
+            });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "git_refs",
-        |language, _diagnostics, _build_ctx, self_property, function| {
+        "change_id",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let index = language.keyword_cache.git_refs_index(language.repo).clone();
-            let out_property = self_property.map(move |commit| index.get(commit.id()).to_vec());
+            let out_property = self_property.map(|commit| commit.change_id().to_owned());
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "git_head",
-        |language, _diagnostics, _build_ctx, self_property, function| {
+        "commit_id",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let repo = language.repo;
-            let out_property = self_property.map(|commit| {
-                let target = repo.view().git_head();
-                target.added_ids().contains(commit.id())
-            });
+            let out_property = self_property.map(|commit| commit.id().to_owned());
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "divergent",
-        |language, _diagnostics, _build_ctx, self_property, function| {
+        "tree_id",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let repo = language.repo;
             let out_property = self_property.map(|commit| {
-                // The given commit could be hidden in e.g. `jj evolog`.
-                let maybe_entries = repo.resolve_change_id(commit.change_id());
-                maybe_entries.map_or(0, |entries| entries.len()) > 1
+                // Conflicted tree is identified by the ids of all terms
+                commit
+                    .tree_id()
+                    .to_merge()
+                    .iter()
+                    .map(|id| id.hex())
+                    .join(",")
             });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "hidden",
-        |language, _diagnostics, _build_ctx, self_property, function| {
+        "change_id_ambiguous",
+        |language, diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
             let repo = language.repo;
-            let out_property = self_property.map(|commit| commit.is_hidden(repo));
-            Ok(out_property.into_dyn_wrapped())
-        },
-    );
-    map.insert(
-        "immutable",
-        |language, _diagnostics, _build_ctx, self_property, function| {
-            function.expect_no_arguments()?;
-            let is_immutable = language
-                .keyword_cache
-                .is_immutable_fn(language, function.name_span)?
-                .clone();
-            let out_property = self_property.and_then(move |commit| Ok(is_immutable(commit.id())?));
+            let index = populate_id_prefix_index(language, diagnostics, function);
+            let out_property = self_property
+                .map(move |commit| is_short_id_ambiguous(commit.change_id(), repo, &index));
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "contained_in",
+        "commit_id_ambiguous",
         |language, diagnostics, _build_ctx, self_property, function| {
-            let [revset_node] = function.expect_exact_arguments()?;
-
-            let is_contained =
-                template_parser::catch_aliases(diagnostics, revset_node, |diagnostics, node| {
-                    let text = template_parser::expect_string_literal(node)?;
-                    let revset = evaluate_user_revset(language, diagnostics, node.span, text)?;
-                    Ok(revset.containing_fn())
-                })?;
-
-            let out_property = self_property.and_then(move |commit| Ok(is_contained(commit.id())?));
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let index = populate_id_prefix_index(language, diagnostics, function);
+            let out_property =
+                self_property.map(move |commit| is_short_id_ambiguous(commit.id(), repo, &index));
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "conflict",
+        "parents",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.and_then(|commit| Ok(commit.has_conflict()?));
+            let out_property = self_property.and_then(|commit| {
+                let commits: Vec<_> = commit.parents().try_collect()?;
+                Ok(commits)
+            });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "empty",
+        "children",
         |language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
             let repo = language.repo;
-            let out_property = self_property.and_then(|commit| Ok(commit.is_empty(repo)?));
+            let out_property = self_property.and_then(move |commit| {
+                let revset = RevsetExpression::commit(commit.id().clone())
+                    .children()
+                    .evaluate(repo)?;
+                let commits: Vec<_> = revset.iter().commits(repo.store()).try_collect()?;
+                Ok(commits)
+            });
             Ok(out_property.into_dyn_wrapped())
         },
     );
     map.insert(
-        "diff",
-        |language, diagnostics, _build_ctx, self_property, function| {
-            let ([], [files_node]) = function.expect_arguments()?;
+        "generation",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.and_then(|commit| {
+                let generation = repo
+                    .index()
+                    .generation_number(commit.id())
+                    .ok_or_else(|| TemplatePropertyError("Commit is not indexed".into()))?;
+                Ok(i64::from(generation))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "ancestor_author_count",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [max_node] = function.expect_exact_arguments()?;
+            let max_property = template_builder::expect_usize_expression(
+                language,
+                diagnostics,
+                build_ctx,
+                max_node,
+            )?;
+            let repo = language.repo;
+            let out_property = (self_property, max_property).and_then(|(commit, max)| {
+                let revset = RevsetExpression::commit(commit.id().clone())
+                    .ancestors()
+                    .minus(&RevsetExpression::root())
+                    .evaluate(repo)?;
+                let emails: HashSet<_> = revset
+                    .iter()
+                    .commits(repo.store())
+                    .take(max)
+                    .map_ok(|commit| commit.author().email.clone())
+                    .try_collect()?;
+                Ok(i64::try_from(emails.len())?)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "parent_change_ids",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|commit| {
+                // The index doesn't provide change ids by commit id, but parent
+                // commits are usually cached by the store.
+                let change_ids: Vec<_> = commit
+                    .parents()
+                    .map_ok(|parent| parent.change_id().clone())
+                    .try_collect()?;
+                Ok(change_ids)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "predecessors",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.and_then(|commit| {
+                // The first entry is the commit itself if it's recorded in the
+                // operation log of the base repo.
+                let Some(entry) =
+                    walk_predecessors(repo.base_repo(), slice::from_ref(commit.id())).next()
+                else {
+                    return Ok(vec![]);
+                };
+                let entry = entry?;
+                let commits: Vec<_> = entry.predecessors().try_collect()?;
+                Ok(commits)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "author",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|commit| commit.author().clone());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "committer",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|commit| commit.committer().clone());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "identical_signatures",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|commit| commit.author() == commit.committer());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "stale",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let days: u32 = language
+                .settings()
+                .get("ui.stale-after-days")
+                .map_err(|err| {
+                    TemplateParseError::expression("Failed to get config value", function.name_span)
+                        .with_source(err)
+                })?;
+            // Compared against the wall clock at the time the template is built.
+            let now = Timestamp::now();
+            let threshold = now.timestamp.0 - i64::from(days) * 24 * 60 * 60 * 1000;
+            let out_property = self_property
+                .map(move |commit| commit.committer().timestamp.timestamp.0 < threshold);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "mine",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let user_email = language.revset_parse_context.user_email.to_owned();
+            let out_property = self_property.map(move |commit| commit.author().email == user_email);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "signature",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(CryptographicSignature::new);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "signature_summary",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let verify: bool = language
+                .settings()
+                .get("ui.verify-signature-summary")
+                .map_err(|err| {
+                    TemplateParseError::expression("Failed to get config value", function.name_span)
+                        .with_source(err)
+                })?;
+            let out_property = self_property.and_then(move |commit| {
+                let Some(sig) = CryptographicSignature::new(commit) else {
+                    return Ok("unsigned".to_owned());
+                };
+                if !verify {
+                    return Ok("signed (unverified)".to_owned());
+                }
+                match sig.status() {
+                    Ok(SigStatus::Good) => Ok("signed".to_owned()),
+                    Ok(status) => Ok(format!("signed ({status})")),
+                    Err(SignError::InvalidSignatureFormat) => Ok("signed (invalid)".to_owned()),
+                    Err(err) => Err(err.into()),
+                }
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "signed_by_key",
+        |_language, diagnostics, _build_ctx, self_property, function| {
+            let [pattern_node] = function.expect_exact_arguments()?;
+            let pattern = expect_string_pattern_literal(diagnostics, pattern_node)?;
+            let out_property = self_property.map(move |commit| {
+                CryptographicSignature::new(commit)
+                    .and_then(|sig| sig.verify().ok())
+                    .filter(|verification| verification.status == SigStatus::Good)
+                    .and_then(|verification| verification.key)
+                    .is_some_and(|key| pattern.is_match(&key))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "working_copies",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.map(|commit| extract_working_copies(repo, &commit));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "working_copy_workspaces",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.map(|commit| {
+                repo.view()
+                    .wc_commit_ids()
+                    .iter()
+                    .filter(|(_, wc_commit_id)| *wc_commit_id == commit.id())
+                    .map(|(name, _)| name.as_str().to_owned())
+                    .collect_vec()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "current_working_copy",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let name = language.workspace_name.clone();
+            let out_property = self_property
+                .map(move |commit| Some(commit.id()) == repo.view().get_wc_commit_id(&name));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "bookmarks",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let index = language
+                .keyword_cache
+                .bookmarks_index(language.repo)
+                .clone();
+            let out_property = self_property.map(move |commit| {
+                index
+                    .get(commit.id())
+                    .iter()
+                    .filter(|commit_ref| commit_ref.is_local() || !commit_ref.synced)
+                    .cloned()
+                    .collect_vec()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "local_bookmarks",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let index = language
+                .keyword_cache
+                .bookmarks_index(language.repo)
+                .clone();
+            let out_property = self_property.map(move |commit| {
+                index
+                    .get(commit.id())
+                    .iter()
+                    .filter(|commit_ref| commit_ref.is_local())
+                    .cloned()
+                    .collect_vec()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "remote_bookmarks",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let index = language
+                .keyword_cache
+                .bookmarks_index(language.repo)
+                .clone();
+            let out_property = self_property.map(move |commit| {
+                index
+                    .get(commit.id())
+                    .iter()
+                    .filter(|commit_ref| commit_ref.is_remote())
+                    .cloned()
+                    .collect_vec()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "tags",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let index = language.keyword_cache.tags_index(language.repo).clone();
+            let out_property = self_property.map(move |commit| index.get(commit.id()).to_vec());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "tags_matching",
+        |language, diagnostics, _build_ctx, self_property, function| {
+            let [pattern_node] = function.expect_exact_arguments()?;
+            let pattern = expect_string_pattern_literal(diagnostics, pattern_node)?;
+            let index = language.keyword_cache.tags_index(language.repo).clone();
+            let out_property = self_property.map(move |commit| {
+                index
+                    .get(commit.id())
+                    .iter()
+                    .filter(|commit_ref| pattern.is_match(commit_ref.name.as_ref()))
+                    .cloned()
+                    .collect_vec()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "git_refs",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let index = language.keyword_cache.git_refs_index(language.repo).clone();
+            let out_property = self_property.map(move |commit| index.get(commit.id()).to_vec());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "git_head",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.map(|commit| {
+                let target = repo.view().git_head();
+                target.added_ids().contains(commit.id())
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "divergent",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.map(|commit| {
+                // The given commit could be hidden in e.g. `jj evolog`.
+                let maybe_entries = repo.resolve_change_id(commit.change_id());
+                maybe_entries.map_or(0, |entries| entries.len()) > 1
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "hidden",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.map(|commit| commit.is_hidden(repo));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "immutable",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let is_immutable = language
+                .keyword_cache
+                .is_immutable_fn(language, function.name_span)?
+                .clone();
+            let out_property = self_property.and_then(move |commit| Ok(is_immutable(commit.id())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "contained_in",
+        |language, diagnostics, _build_ctx, self_property, function| {
+            let [revset_node] = function.expect_exact_arguments()?;
+
+            let is_contained =
+                template_parser::catch_aliases(diagnostics, revset_node, |diagnostics, node| {
+                    let text = template_parser::expect_string_literal(node)?;
+                    let revset = evaluate_user_revset(language, diagnostics, node.span, text)?;
+                    Ok(revset.containing_fn())
+                })?;
+
+            let out_property = self_property.and_then(move |commit| Ok(is_contained(commit.id())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "is_ancestor_of",
+        |language, diagnostics, _build_ctx, self_property, function| {
+            let [revset_node] = function.expect_exact_arguments()?;
+
+            let is_ancestor =
+                template_parser::catch_aliases(diagnostics, revset_node, |diagnostics, node| {
+                    let text = template_parser::expect_string_literal(node)?;
+                    let expression = parse_user_revset(language, diagnostics, node.span, text)?;
+                    let revset =
+                        evaluate_revset_expression(language, node.span, &expression.ancestors())?;
+                    Ok(revset.containing_fn())
+                })?;
+
+            let out_property = self_property.and_then(move |commit| Ok(is_ancestor(commit.id())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "is_descendant_of",
+        |language, diagnostics, _build_ctx, self_property, function| {
+            let [revset_node] = function.expect_exact_arguments()?;
+
+            let is_descendant =
+                template_parser::catch_aliases(diagnostics, revset_node, |diagnostics, node| {
+                    let text = template_parser::expect_string_literal(node)?;
+                    let expression = parse_user_revset(language, diagnostics, node.span, text)?;
+                    let revset =
+                        evaluate_revset_expression(language, node.span, &expression.descendants())?;
+                    Ok(revset.containing_fn())
+                })?;
+
+            let out_property =
+                self_property.and_then(move |commit| Ok(is_descendant(commit.id())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "descendant_count",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [max_node] = function.expect_exact_arguments()?;
+            let max_property = template_builder::expect_usize_expression(
+                language,
+                diagnostics,
+                build_ctx,
+                max_node,
+            )?;
+            let repo = language.repo;
+            let out_property = (self_property, max_property).and_then(|(commit, max)| {
+                // Stop walking as soon as the cap is reached
+                let revset = RevsetExpression::commit(commit.id().clone())
+                    .children()
+                    .descendants()
+                    .evaluate(repo)?;
+                let count = revset.iter().take(max).process_results(|ids| ids.count())?;
+                Ok(i64::try_from(count)?)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "descendant_bookmarks",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [max_node] = function.expect_exact_arguments()?;
+            let max_property = template_builder::expect_usize_expression(
+                language,
+                diagnostics,
+                build_ctx,
+                max_node,
+            )?;
+            let repo = language.repo;
+            let index = language
+                .keyword_cache
+                .bookmarks_index(language.repo)
+                .clone();
+            let out_property = (self_property, max_property).and_then(move |(commit, max)| {
+                // Only the first `max` descendants are looked up
+                let revset = RevsetExpression::commit(commit.id().clone())
+                    .children()
+                    .descendants()
+                    .evaluate(repo)?;
+                let commit_refs: Vec<_> = revset.iter().take(max).process_results(|ids| {
+                    ids.flat_map(|id| index.get(&id))
+                        .filter(|commit_ref| commit_ref.is_local())
+                        .cloned()
+                        .collect()
+                })?;
+                Ok(commit_refs)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "closest_bookmark",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let ([], [max_distance_node]) = function.expect_arguments()?;
+            let max_distance_property = max_distance_node
+                .map(|node| {
+                    template_builder::expect_usize_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let repo = language.repo;
+            let index = language
+                .keyword_cache
+                .bookmarks_index(language.repo)
+                .clone();
+            let out_property =
+                (self_property, max_distance_property).and_then(move |(commit, max_distance)| {
+                    Ok(find_closest_bookmark(repo, &index, commit, max_distance)?)
+                });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "conflict",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|commit| Ok(commit.has_conflict()?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "conflict_types",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|commit| Ok(describe_conflict_types(&commit.tree()?)?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "is_conflict_resolution",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|commit| Ok(is_conflict_resolution(&commit)?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "empty",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.and_then(|commit| Ok(commit.is_empty(repo)?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "has_description",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|commit| !commit.description().trim().is_empty());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "diff",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let ([], [files_node, detect_renames_node]) =
+                function.expect_named_arguments(&["", "detect_renames"])?;
             let files = if let Some(node) = files_node {
                 expect_fileset_literal(diagnostics, node, language.path_converter)?
             } else {
@@ -1142,10 +1961,82 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
                 // https://github.com/jj-vcs/jj/issues/2933#issuecomment-1925870731
                 FilesetExpression::all()
             };
+            let detect_renames_property = detect_renames_node
+                .map(|node| {
+                    template_builder::expect_boolean_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
             let repo = language.repo;
             let matcher: Rc<dyn Matcher> = files.to_matcher().into();
-            let out_property = self_property
-                .and_then(move |commit| Ok(TreeDiff::from_commit(repo, &commit, matcher.clone())?));
+            let out_property = (self_property, detect_renames_property).and_then(
+                move |(commit, detect_renames)| {
+                    let detect_renames = detect_renames.unwrap_or(true);
+                    Ok(TreeDiff::from_commit(
+                        repo,
+                        &commit,
+                        matcher.clone(),
+                        detect_renames,
+                    )?)
+                },
+            );
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "adds_files",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let matcher: Rc<dyn Matcher> = Rc::new(EverythingMatcher);
+            let out_property = self_property.and_then(move |commit| {
+                let diff = TreeDiff::from_commit(repo, &commit, matcher.clone(), true)?;
+                Ok(diff.adds_files().block_on()?)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "churn",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let conflict_marker_style = language.conflict_marker_style;
+            let out_property = self_property.and_then(move |commit| {
+                let churn = count_churn(repo, &commit, conflict_marker_style)?;
+                Ok(i64::try_from(churn)?)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "review_size_label",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let thresholds: [u64; 4] = language
+                .settings()
+                .get("ui.review-size-thresholds")
+                .map_err(|err| {
+                    TemplateParseError::expression("Failed to get config value", function.name_span)
+                        .with_source(err)
+                })?;
+            let repo = language.repo;
+            let conflict_marker_style = language.conflict_marker_style;
+            let out_property = self_property.and_then(move |commit| {
+                let churn = u64::try_from(count_churn(repo, &commit, conflict_marker_style)?)?;
+                let label = match thresholds.iter().position(|&max| churn <= max) {
+                    Some(0) => "XS",
+                    Some(1) => "S",
+                    Some(2) => "M",
+                    Some(_) => "L",
+                    None => "XL",
+                };
+                Ok(label.to_owned())
+            });
             Ok(out_property.into_dyn_wrapped())
         },
     );
@@ -1183,9 +2074,83 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "is_merge",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|commit| commit.parent_ids().len() > 1);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
+fn first_line(description: &str) -> &str {
+    description.lines().next().unwrap_or("")
+}
+
+/// Guesses whether the first line of the description starts with a verb in
+/// imperative mood.
+///
+/// A leading `topic:` prefix is skipped. The first word is rejected if it ends
+/// with "ed" or "ing" (e.g. "Added", "Fixing"), unless it is a known verb
+/// such as "Embed" or "Bring". Empty subjects aren't imperative.
+fn is_imperative_subject(description: &str) -> bool {
+    const EXCEPTIONS: &[&str] = &[
+        "bring", "embed", "exceed", "feed", "need", "proceed", "seed", "shed", "speed", "string",
+        "succeed",
+    ];
+    let subject = first_line(description);
+    let mut words = subject.split_whitespace();
+    let Some(mut word) = words.next() else {
+        return false;
+    };
+    if word.ends_with(':') {
+        let Some(next) = words.next() else {
+            return false;
+        };
+        word = next;
+    }
+    let word = word.to_lowercase();
+    if !word.starts_with(|c: char| c.is_alphabetic()) {
+        return false;
+    }
+    EXCEPTIONS.contains(&word.as_str()) || !(word.ends_with("ed") || word.ends_with("ing"))
+}
+
+/// Parses the subject of the commit to be squashed into from the first line of
+/// an autosquash-style `fixup! <subject>`, `squash! <subject>`, or
+/// `amend! <subject>` description. Repeated prefixes are stripped.
+fn parse_fixup_target_subject(description: &str) -> Option<&str> {
+    const PREFIXES: &[&str] = &["fixup! ", "squash! ", "amend! "];
+    let strip = |subject: &str| -> Option<&str> {
+        PREFIXES
+            .iter()
+            .find_map(|prefix| subject.strip_prefix(prefix))
+    };
+    let mut subject = strip(first_line(description))?;
+    while let Some(rest) = strip(subject) {
+        subject = rest;
+    }
+    Some(subject)
+}
+
+/// Parses the target commit id from the description of a revert commit, which
+/// contains a "This backs out commit <hash>." or "This reverts commit <hash>."
+/// line.
+fn parse_reverted_commit_id(description: &str, commit_id_length: usize) -> Option<CommitId> {
+    description.lines().find_map(|line| {
+        let rest = line
+            .strip_prefix("This backs out commit ")
+            .or_else(|| line.strip_prefix("This reverts commit "))?;
+        let hex = rest.strip_suffix('.').unwrap_or(rest);
+        if hex.len() != commit_id_length * 2 {
+            return None;
+        }
+        CommitId::try_from_hex(hex)
+    })
+}
+
 fn extract_working_copies(repo: &dyn Repo, commit: &Commit) -> Vec<WorkspaceRef> {
     if repo.view().wc_commit_ids().len() <= 1 {
         // No non-default working copies, return empty list.
@@ -1219,6 +2184,18 @@ fn expect_fileset_literal(
     })
 }
 
+fn expect_string_pattern_literal(
+    diagnostics: &mut TemplateDiagnostics,
+    node: &ExpressionNode,
+) -> Result<StringPattern, TemplateParseError> {
+    template_parser::catch_aliases(diagnostics, node, |_diagnostics, node| {
+        let text = template_parser::expect_string_literal(node)?;
+        StringPattern::parse(text).map_err(|err| {
+            TemplateParseError::expression("Invalid string pattern", node.span).with_source(err)
+        })
+    })
+}
+
 fn evaluate_revset_expression<'repo>(
     language: &CommitTemplateLanguage<'repo>,
     span: pest::Span<'_>,
@@ -1245,6 +2222,16 @@ fn evaluate_user_revset<'repo>(
     span: pest::Span<'_>,
     revset: &str,
 ) -> Result<Box<dyn Revset + 'repo>, TemplateParseError> {
+    let expression = parse_user_revset(language, diagnostics, span, revset)?;
+    evaluate_revset_expression(language, span, &expression)
+}
+
+fn parse_user_revset(
+    language: &CommitTemplateLanguage<'_>,
+    diagnostics: &mut TemplateDiagnostics,
+    span: pest::Span<'_>,
+    revset: &str,
+) -> Result<Rc<UserRevsetExpression>, TemplateParseError> {
     let mut inner_diagnostics = RevsetDiagnostics::new();
     let (expression, modifier) = revset::parse_with_modifier(
         &mut inner_diagnostics,
@@ -1256,8 +2243,7 @@ fn evaluate_user_revset<'repo>(
         TemplateParseError::expression("In revset expression", span).with_source(diag)
     });
     let (None | Some(RevsetModifier::All)) = modifier;
-
-    evaluate_revset_expression(language, span, &expression)
+    Ok(expression)
 }
 
 /// Bookmark or tag name with metadata.
@@ -1558,6 +2544,16 @@ fn builtin_commit_ref_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "name_matches",
+        |_language, diagnostics, _build_ctx, self_property, function| {
+            let [pattern_node] = function.expect_exact_arguments()?;
+            let pattern = expect_string_pattern_literal(diagnostics, pattern_node)?;
+            let out_property =
+                self_property.map(move |commit_ref| pattern.is_match(commit_ref.name.as_ref()));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "remote",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -1566,6 +2562,25 @@ fn builtin_commit_ref_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "remote_url",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let urls = language
+                .keyword_cache
+                .git_remote_urls(language.repo.store())
+                .map_err(|err| {
+                    TemplateParseError::expression("Failed to load Git remotes", function.name_span)
+                        .with_source(err)
+                })?
+                .clone();
+            let out_property = self_property.map(move |commit_ref| {
+                let remote: &str = commit_ref.remote.as_ref()?.as_ref();
+                urls.get(remote).cloned()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "present",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -1620,6 +2635,15 @@ fn builtin_commit_ref_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "conflict_side_count",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property
+                .and_then(|commit_ref| Ok(i64::try_from(commit_ref.target.added_ids().count())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "tracked",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -1659,6 +2683,68 @@ fn builtin_commit_ref_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
     map
 }
 
+fn builtin_commit_list_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Vec<Commit>> {
+    let mut map: CommitTemplateBuildMethodFnMap<Vec<Commit>> =
+        template_builder::builtin_unformattable_list_methods();
+    map.insert("find", template_builder::build_find_method);
+    map.insert("group_by", template_builder::build_group_by_method);
+    map.insert("max_by", template_builder::build_max_by_method);
+    map.insert("min_by", template_builder::build_min_by_method);
+    map.insert("partition", template_builder::build_partition_method);
+    map
+}
+
+fn builtin_commit_ref_list_methods<'repo>()
+-> CommitTemplateBuildMethodFnMap<'repo, Vec<Rc<CommitRef>>> {
+    let mut map: CommitTemplateBuildMethodFnMap<Vec<Rc<CommitRef>>> =
+        template_builder::builtin_formattable_list_methods();
+    map.insert("find", template_builder::build_find_method);
+    map.insert("max_by", template_builder::build_max_by_method);
+    map.insert("min_by", template_builder::build_min_by_method);
+    map
+}
+
+fn builtin_workspace_ref_list_methods<'repo>()
+-> CommitTemplateBuildMethodFnMap<'repo, Vec<WorkspaceRef>> {
+    let mut map: CommitTemplateBuildMethodFnMap<Vec<WorkspaceRef>> =
+        template_builder::builtin_formattable_list_methods();
+    map.insert("find", template_builder::build_find_method);
+    map
+}
+
+/// Finds a local bookmark on the nearest ancestor of the `commit`, in terms of
+/// the number of parent edges. Ties are broken by parent order.
+fn find_closest_bookmark(
+    repo: &dyn Repo,
+    index: &CommitRefsIndex,
+    commit: Commit,
+    max_distance: Option<usize>,
+) -> BackendResult<Option<Rc<CommitRef>>> {
+    let mut visited = HashSet::from([commit.id().clone()]);
+    let mut generation = vec![commit];
+    let mut distance = 0;
+    while !generation.is_empty() && max_distance.is_none_or(|max| distance <= max) {
+        let found = generation.iter().find_map(|commit| {
+            index
+                .get(commit.id())
+                .iter()
+                .find(|commit_ref| commit_ref.is_local())
+        });
+        if let Some(commit_ref) = found {
+            return Ok(Some(commit_ref.clone()));
+        }
+        let mut parents = vec![];
+        for id in generation.iter().flat_map(|commit| commit.parent_ids()) {
+            if visited.insert(id.clone()) {
+                parents.push(repo.store().get_commit(id)?);
+            }
+        }
+        generation = parents;
+        distance += 1;
+    }
+    Ok(None)
+}
+
 /// Cache for reverse lookup refs.
 #[derive(Clone, Debug, Default)]
 pub struct CommitRefsIndex {
@@ -1761,9 +2847,88 @@ fn builtin_repo_path_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, R
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "depth",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|path| Ok(i64::try_from(path.components().count())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "relative_to",
+        |_language, diagnostics, _build_ctx, self_property, function| {
+            let [dir_node] = function.expect_exact_arguments()?;
+            let dir =
+                template_parser::catch_aliases(diagnostics, dir_node, |_diagnostics, node| {
+                    let text = template_parser::expect_string_literal(node)?;
+                    RepoPathBuf::from_internal_string(text.trim_end_matches('/')).map_err(|err| {
+                        TemplateParseError::expression("Invalid directory path", node.span)
+                            .with_source(err)
+                    })
+                })?;
+            let out_property = self_property.map(move |path| match path.strip_prefix(&dir) {
+                Some(relative) => relative.to_owned(),
+                None => path,
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
+/// Extracts the email address from `Name <email>` identity, which is used to
+/// identify the signer. Falls back to the whole value if there's no address.
+fn signed_off_by_email(value: &str) -> String {
+    let email = value
+        .rsplit_once('<')
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(value, |(email, _)| email);
+    email.trim().to_ascii_lowercase()
+}
+
+/// Length of ids printed by `.short()` by default.
+const DEFAULT_SHORT_ID_LEN: usize = 12;
+
+fn populate_id_prefix_index<'repo>(
+    language: &CommitTemplateLanguage<'repo>,
+    diagnostics: &mut TemplateDiagnostics,
+    function: &FunctionCallNode,
+) -> IdPrefixIndex<'repo> {
+    match language.id_prefix_context.populate(language.repo) {
+        Ok(index) => index,
+        Err(err) => {
+            // Not an error because we can still produce somewhat
+            // reasonable output.
+            diagnostics.add_warning(
+                TemplateParseError::expression(
+                    "Failed to load short-prefixes index",
+                    function.name_span,
+                )
+                .with_source(err),
+            );
+            IdPrefixIndex::empty()
+        }
+    }
+}
+
+/// Returns true if the id abbreviated by `.short()` can't be resolved to the
+/// original id, either because the prefix is shared with other ids, or it is
+/// shadowed by a tag or bookmark of the same name.
+fn is_short_id_ambiguous<O>(id: &O, repo: &dyn Repo, index: &IdPrefixIndex) -> bool
+where
+    O: Display + ShortestIdPrefixLen,
+{
+    if id.shortest_prefix_len(repo, index) > DEFAULT_SHORT_ID_LEN {
+        return true;
+    }
+    let short = format!("{id:.DEFAULT_SHORT_ID_LEN$}");
+    let view = repo.view();
+    view.get_tag(short.as_ref()).is_present()
+        || view.get_local_bookmark(short.as_ref()).is_present()
+}
+
 trait ShortestIdPrefixLen {
     fn shortest_prefix_len(&self, repo: &dyn Repo, index: &IdPrefixIndex) -> usize;
 }
@@ -1780,6 +2945,12 @@ impl Template for ChangeId {
     }
 }
 
+impl Template for Vec<ChangeId> {
+    fn format(&self, formatter: &mut TemplateFormatter) -> io::Result<()> {
+        templater::format_joined(formatter, self, " ")
+    }
+}
+
 fn builtin_change_id_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, ChangeId> {
     let mut map = builtin_commit_or_change_id_methods::<ChangeId>();
     map.insert(
@@ -1828,11 +2999,19 @@ fn builtin_commit_id_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, C
 
 fn builtin_commit_or_change_id_methods<'repo, O>() -> CommitTemplateBuildMethodFnMap<'repo, O>
 where
-    O: Display + ShortestIdPrefixLen + 'repo,
+    O: Display + ObjectId + ShortestIdPrefixLen + 'repo,
 {
     // Not using maplit::hashmap!{} or custom declarative macro here because
     // code completion inside macro is quite restricted.
     let mut map = CommitTemplateBuildMethodFnMap::<O>::new();
+    map.insert(
+        "bytes",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|id| BString::from(id.as_bytes()));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "short",
         |language, diagnostics, build_ctx, self_property, function| {
@@ -1848,7 +3027,7 @@ where
                 })
                 .transpose()?;
             let out_property = (self_property, len_property)
-                .map(|(id, len)| format!("{id:.len$}", len = len.unwrap_or(12)));
+                .map(|(id, len)| format!("{id:.len$}", len = len.unwrap_or(DEFAULT_SHORT_ID_LEN)));
             Ok(out_property.into_dyn_wrapped())
         },
     );
@@ -1867,21 +3046,7 @@ where
                 })
                 .transpose()?;
             let repo = language.repo;
-            let index = match language.id_prefix_context.populate(repo) {
-                Ok(index) => index,
-                Err(err) => {
-                    // Not an error because we can still produce somewhat
-                    // reasonable output.
-                    diagnostics.add_warning(
-                        TemplateParseError::expression(
-                            "Failed to load short-prefixes index",
-                            function.name_span,
-                        )
-                        .with_source(err),
-                    );
-                    IdPrefixIndex::empty()
-                }
-            };
+            let index = populate_id_prefix_index(language, diagnostics, function);
             // The length of the id printed will be the maximum of the minimum
             // `len` and the length of the shortest unique prefix.
             let out_property = (self_property, len_property).map(move |(id, len)| {
@@ -1923,6 +3088,13 @@ impl ShortestIdPrefix {
             rest: self.rest.to_ascii_lowercase(),
         }
     }
+
+    /// Concatenates the prefix and the rest enclosed in the `open` and `close`
+    /// delimiters.
+    fn to_highlighted(&self, open: &str, close: &str) -> String {
+        let Self { prefix, rest } = self;
+        format!("{prefix}{open}{rest}{close}")
+    }
 }
 
 fn builtin_shortest_id_prefix_methods<'repo>()
@@ -1962,6 +3134,31 @@ fn builtin_shortest_id_prefix_methods<'repo>()
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "len",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|id| Ok(i64::try_from(id.prefix.len() + id.rest.len())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "highlighted",
+        |_language, diagnostics, _build_ctx, self_property, function| {
+            let [open_node, close_node] = function.expect_exact_arguments()?;
+            let open =
+                template_parser::catch_aliases(diagnostics, open_node, |_diagnostics, node| {
+                    Ok(template_parser::expect_string_literal(node)?.to_owned())
+                })?;
+            let close =
+                template_parser::catch_aliases(diagnostics, close_node, |_diagnostics, node| {
+                    Ok(template_parser::expect_string_literal(node)?.to_owned())
+                })?;
+            let out_property = self_property.map(move |id| id.to_highlighted(&open, &close));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
@@ -1979,12 +3176,15 @@ impl TreeDiff {
         repo: &dyn Repo,
         commit: &Commit,
         matcher: Rc<dyn Matcher>,
+        detect_renames: bool,
     ) -> BackendResult<Self> {
         let mut copy_records = CopyRecords::default();
-        for parent in commit.parent_ids() {
-            let records =
-                diff_util::get_copy_records(repo.store(), parent, commit.id(), &*matcher)?;
-            copy_records.add_records(records)?;
+        if detect_renames {
+            for parent in commit.parent_ids() {
+                let records =
+                    diff_util::get_copy_records(repo.store(), parent, commit.id(), &*matcher)?;
+                copy_records.add_records(records)?;
+            }
         }
         Ok(Self {
             from_tree: commit.parent_tree(repo)?,
@@ -2006,12 +3206,46 @@ impl TreeDiff {
             .await
     }
 
+    /// Returns true if there are no changed files. Stops at the first entry.
+    async fn is_empty(&self) -> BackendResult<bool> {
+        match self.diff_stream().next().await {
+            Some(entry) => entry.values.map(|_| false),
+            None => Ok(true),
+        }
+    }
+
+    /// Returns true if any file is added or copied. Stops at the first match.
+    async fn adds_files(&self) -> BackendResult<bool> {
+        let mut entries = self
+            .diff_stream()
+            .map(TreeDiffEntry::from_backend_entry_with_copies);
+        while let Some(entry) = entries.next().await {
+            if matches!(entry?.status_label(), "added" | "copied") {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    async fn collect_lines(
+        &self,
+        conflict_marker_style: ConflictMarkerStyle,
+    ) -> BackendResult<DiffLines> {
+        let store = self.from_tree.store();
+        let options = diff_util::LineDiffOptions::default();
+        DiffLines::calculate(store, self.diff_stream(), &options, conflict_marker_style).await
+    }
+
     fn into_formatted<F, E>(self, show: F) -> TreeDiffFormatted<F>
     where
         F: Fn(&mut dyn Formatter, &Store, BoxStream<CopiesTreeDiffEntry>) -> Result<(), E>,
         E: Into<TemplatePropertyError>,
     {
-        TreeDiffFormatted { diff: self, show }
+        TreeDiffFormatted {
+            diff: self,
+            show,
+            max_lines: None,
+        }
     }
 }
 
@@ -2019,6 +3253,14 @@ impl TreeDiff {
 struct TreeDiffFormatted<F> {
     diff: TreeDiff,
     show: F,
+    max_lines: Option<usize>,
+}
+
+impl<F> TreeDiffFormatted<F> {
+    /// Truncates the rendered output after `max_lines` lines.
+    fn with_max_lines(self, max_lines: Option<usize>) -> Self {
+        Self { max_lines, ..self }
+    }
 }
 
 impl<F, E> Template for TreeDiffFormatted<F>
@@ -2030,7 +3272,45 @@ where
         let show = &self.show;
         let store = self.diff.from_tree.store();
         let tree_diff = self.diff.diff_stream();
-        show(formatter.as_mut(), store, tree_diff).or_else(|err| formatter.handle_error(err.into()))
+        let Some(max_lines) = self.max_lines else {
+            return show(formatter.as_mut(), store, tree_diff)
+                .or_else(|err| formatter.handle_error(err.into()));
+        };
+        let mut recorder = FormatRecorder::new();
+        let result = show(&mut recorder, store, tree_diff);
+        let data = recorder.data();
+        let num_lines = data.lines_with_terminator().count();
+        let truncated_end = data
+            .lines_with_terminator()
+            .take(max_lines)
+            .map(|line| line.len())
+            .sum();
+        recorder.replay_with(formatter.as_mut(), |formatter, range| {
+            let end = min(range.end, truncated_end);
+            if range.start < end {
+                formatter.write_all(&data[range.start..end])?;
+            }
+            Ok(())
+        })?;
+        if num_lines > max_lines {
+            let num_elided = num_lines - max_lines;
+            writeln!(formatter.labeled("elided"), "… {num_elided} more lines")?;
+        }
+        result.or_else(|err| formatter.handle_error(err.into()))
+    }
+}
+
+/// Overrides the line comparison mode in the same way as the `diff` command
+/// arguments.
+fn merge_line_compare_args(
+    options: &mut diff_util::LineDiffOptions,
+    ignore_all_space: Option<bool>,
+    ignore_space_change: Option<bool>,
+) {
+    if ignore_all_space == Some(true) {
+        options.compare_mode = diff_util::LineCompareMode::IgnoreAllSpace;
+    } else if ignore_space_change == Some(true) {
+        options.compare_mode = diff_util::LineCompareMode::IgnoreSpaceChange;
     }
 }
 
@@ -2049,10 +3329,31 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "empty",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|diff| Ok(diff.is_empty().block_on()?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "color_words",
         |language, diagnostics, build_ctx, self_property, function| {
-            let ([], [context_node]) = function.expect_arguments()?;
+            let (
+                [],
+                [
+                    context_node,
+                    ignore_all_space_node,
+                    ignore_space_change_node,
+                    max_lines_node,
+                ],
+            ) = function.expect_named_arguments(&[
+                "",
+                "ignore_all_space",
+                "ignore_space_change",
+                "max_lines",
+            ])?;
             let context_property = context_node
                 .map(|node| {
                     template_builder::expect_usize_expression(
@@ -2063,6 +3364,36 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                     )
                 })
                 .transpose()?;
+            let ignore_all_space_property = ignore_all_space_node
+                .map(|node| {
+                    template_builder::expect_boolean_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let ignore_space_change_property = ignore_space_change_node
+                .map(|node| {
+                    template_builder::expect_boolean_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let max_lines_property = max_lines_node
+                .map(|node| {
+                    template_builder::expect_usize_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
             let path_converter = language.path_converter;
             let options = diff_util::ColorWordsDiffOptions::from_settings(language.settings())
                 .map_err(|err| {
@@ -2070,24 +3401,38 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                     TemplateParseError::expression(message, function.name_span).with_source(err)
                 })?;
             let conflict_marker_style = language.conflict_marker_style;
-            let template = (self_property, context_property)
-                .map(move |(diff, context)| {
-                    let mut options = options.clone();
-                    if let Some(context) = context {
-                        options.context = context;
-                    }
-                    diff.into_formatted(move |formatter, store, tree_diff| {
-                        diff_util::show_color_words_diff(
-                            formatter,
-                            store,
-                            tree_diff,
-                            path_converter,
-                            &options,
-                            conflict_marker_style,
-                        )
-                        .block_on()
-                    })
-                })
+            let template = (
+                self_property,
+                context_property,
+                ignore_all_space_property,
+                ignore_space_change_property,
+                max_lines_property,
+            )
+                .map(
+                    move |(diff, context, ignore_all_space, ignore_space_change, max_lines)| {
+                        let mut options = options.clone();
+                        if let Some(context) = context {
+                            options.context = context;
+                        }
+                        merge_line_compare_args(
+                            &mut options.line_diff,
+                            ignore_all_space,
+                            ignore_space_change,
+                        );
+                        diff.into_formatted(move |formatter, store, tree_diff| {
+                            diff_util::show_color_words_diff(
+                                formatter,
+                                store,
+                                tree_diff,
+                                path_converter,
+                                &options,
+                                conflict_marker_style,
+                            )
+                            .block_on()
+                        })
+                        .with_max_lines(max_lines)
+                    },
+                )
                 .into_template();
             Ok(P::wrap_template(template))
         },
@@ -2095,7 +3440,24 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
     map.insert(
         "git",
         |language, diagnostics, build_ctx, self_property, function| {
-            let ([], [context_node]) = function.expect_arguments()?;
+            let (
+                [],
+                [
+                    context_node,
+                    ignore_all_space_node,
+                    ignore_space_change_node,
+                    abbrev_node,
+                    full_index_node,
+                    stat_node,
+                ],
+            ) = function.expect_named_arguments(&[
+                "",
+                "ignore_all_space",
+                "ignore_space_change",
+                "abbrev",
+                "full_index",
+                "stat",
+            ])?;
             let context_property = context_node
                 .map(|node| {
                     template_builder::expect_usize_expression(
@@ -2106,29 +3468,135 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                     )
                 })
                 .transpose()?;
+            let ignore_all_space_property = ignore_all_space_node
+                .map(|node| {
+                    template_builder::expect_boolean_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let ignore_space_change_property = ignore_space_change_node
+                .map(|node| {
+                    template_builder::expect_boolean_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let abbrev_property = abbrev_node
+                .map(|node| {
+                    template_builder::expect_usize_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let full_index_property = full_index_node
+                .map(|node| {
+                    template_builder::expect_boolean_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let stat_property = stat_node
+                .map(|node| {
+                    template_builder::expect_boolean_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
             let options = diff_util::UnifiedDiffOptions::from_settings(language.settings())
                 .map_err(|err| {
                     let message = "Failed to load diff settings";
                     TemplateParseError::expression(message, function.name_span).with_source(err)
                 })?;
+            let path_converter = language.path_converter;
             let conflict_marker_style = language.conflict_marker_style;
-            let template = (self_property, context_property)
-                .map(move |(diff, context)| {
-                    let mut options = options.clone();
-                    if let Some(context) = context {
-                        options.context = context;
-                    }
-                    diff.into_formatted(move |formatter, store, tree_diff| {
-                        diff_util::show_git_diff(
-                            formatter,
-                            store,
-                            tree_diff,
-                            &options,
-                            conflict_marker_style,
-                        )
-                        .block_on()
-                    })
-                })
+            let template = (
+                self_property,
+                context_property,
+                (ignore_all_space_property, ignore_space_change_property),
+                (abbrev_property, full_index_property),
+                stat_property,
+            )
+                .and_then(
+                    move |(
+                        diff,
+                        context,
+                        (ignore_all_space, ignore_space_change),
+                        (abbrev, full_index),
+                        stat,
+                    )| {
+                        let mut options = options.clone();
+                        if let Some(context) = context {
+                            options.context = context;
+                        }
+                        if let Some(abbrev) = abbrev {
+                            options.index_hash_len = Some(abbrev);
+                        }
+                        if full_index == Some(true) {
+                            options.index_hash_len = None;
+                        }
+                        merge_line_compare_args(
+                            &mut options.line_diff,
+                            ignore_all_space,
+                            ignore_space_change,
+                        );
+                        // The diff stream can't be reused, so stats are
+                        // calculated upfront.
+                        let stats = if stat == Some(true) {
+                            let store = diff.from_tree.store();
+                            let stat_options = diff_util::DiffStatOptions {
+                                line_diff: options.line_diff.clone(),
+                            };
+                            let stats = DiffStats::calculate(
+                                store,
+                                diff.diff_stream(),
+                                &stat_options,
+                                conflict_marker_style,
+                            )
+                            .block_on()?;
+                            Some(stats)
+                        } else {
+                            None
+                        };
+                        Ok(diff.into_formatted(move |formatter, store, tree_diff| {
+                            if let Some(stats) = &stats {
+                                // Fixed width as "git format-patch" does, so
+                                // the patch doesn't depend on the terminal.
+                                diff_util::show_diff_stats(
+                                    formatter,
+                                    stats,
+                                    path_converter,
+                                    72,
+                                    DiffStatBarChars::default(),
+                                )?;
+                                writeln!(formatter)?;
+                            }
+                            diff_util::show_git_diff(
+                                formatter,
+                                store,
+                                tree_diff,
+                                &options,
+                                conflict_marker_style,
+                            )
+                            .block_on()
+                        }))
+                    },
+                )
                 .into_template();
             Ok(P::wrap_template(template))
         },
@@ -2136,7 +3604,8 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
     map.insert(
         "stat",
         |language, diagnostics, build_ctx, self_property, function| {
-            let ([], [width_node]) = function.expect_arguments()?;
+            let ([], [width_node, bar_node]) =
+                function.expect_named_arguments(&["width", "bar"])?;
             let width_property = width_node
                 .map(|node| {
                     template_builder::expect_usize_expression(
@@ -2147,10 +3616,25 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                     )
                 })
                 .transpose()?;
+            let bar_chars = bar_node
+                .map(|node| {
+                    template_parser::catch_aliases(diagnostics, node, |_diagnostics, node| {
+                        let chars = template_parser::expect_string_literal(node)?;
+                        parse_diff_stat_bar_chars(chars).ok_or_else(|| {
+                            TemplateParseError::expression(
+                                "Expected two single-width characters",
+                                node.span,
+                            )
+                        })
+                    })
+                })
+                .transpose()?
+                .unwrap_or_default();
             let path_converter = language.path_converter;
             // No user configuration exists for diff stat.
             let options = diff_util::DiffStatOptions::default();
             let conflict_marker_style = language.conflict_marker_style;
+            let term_width = language.term_width;
             // TODO: cache and reuse stats within the current evaluation?
             let out_property = (self_property, width_property).and_then(move |(diff, width)| {
                 let store = diff.from_tree.store();
@@ -2160,8 +3644,8 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                 Ok(DiffStatsFormatted {
                     stats,
                     path_converter,
-                    // TODO: fall back to current available width
-                    width: width.unwrap_or(80),
+                    width: width.unwrap_or(term_width),
+                    bar_chars,
                 })
             });
             Ok(out_property.into_dyn_wrapped())
@@ -2183,10 +3667,65 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
             Ok(P::wrap_template(template))
         },
     );
+    map.insert(
+        "num_hunks",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let options = diff_util::UnifiedDiffOptions::from_settings(language.settings())
+                .map_err(|err| {
+                    let message = "Failed to load diff settings";
+                    TemplateParseError::expression(message, function.name_span).with_source(err)
+                })?;
+            let conflict_marker_style = language.conflict_marker_style;
+            let out_property = self_property.and_then(move |diff| {
+                let store = diff.from_tree.store();
+                let num_hunks = diff_util::count_unified_diff_hunks(
+                    store,
+                    diff.diff_stream(),
+                    &options,
+                    conflict_marker_style,
+                )
+                .block_on()?;
+                Ok(i64::try_from(num_hunks)?)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "added_lines",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let conflict_marker_style = language.conflict_marker_style;
+            let out_property = self_property.and_then(move |diff| {
+                let lines = diff.collect_lines(conflict_marker_style).block_on()?;
+                Ok(lines_to_strings(lines.added))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "removed_lines",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let conflict_marker_style = language.conflict_marker_style;
+            let out_property = self_property.and_then(move |diff| {
+                let lines = diff.collect_lines(conflict_marker_style).block_on()?;
+                Ok(lines_to_strings(lines.removed))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     // TODO: add support for external tools
     map
 }
 
+fn lines_to_strings(lines: Vec<BString>) -> Vec<String> {
+    lines
+        .into_iter()
+        .map(|line| line.to_str_lossy().into_owned())
+        .collect()
+}
+
 /// [`MergedTree`] diff entry.
 #[derive(Clone, Debug)]
 pub struct TreeDiffEntry {
@@ -2214,6 +3753,39 @@ impl TreeDiffEntry {
         label
     }
 
+    fn status_char(&self) -> char {
+        let (_label, sigil) = diff_util::diff_status_label_and_char(
+            &self.path,
+            &self.source_value,
+            &self.target_value,
+        );
+        sigil
+    }
+
+    async fn is_binary(&self, store: &Store) -> BackendResult<bool> {
+        let target_path = &self.path.target;
+        let source_path = self
+            .path
+            .source
+            .as_ref()
+            .map_or(target_path, |(path, _)| path);
+        let sides = [
+            (source_path, &self.source_value),
+            (target_path, &self.target_value),
+        ];
+        for (path, value) in sides {
+            // Conflicted values are binary if any of the sides is binary.
+            for term in value.iter().flatten() {
+                if let TreeValue::File { id, .. } = term {
+                    if diff_util::is_binary_file(store, path, id).await? {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
+
     fn into_source_entry(self) -> TreeEntry {
         TreeEntry {
             path: self.path.source.map_or(self.path.target, |(path, _)| path),
@@ -2250,7 +3822,42 @@ fn builtin_tree_diff_entry_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'r
             Ok(out_property.into_dyn_wrapped())
         },
     );
-    // TODO: add status_code() or status_char()?
+    map.insert(
+        "status_char",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|entry| entry.status_char().to_string());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "is_binary",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let store = language.repo.store().clone();
+            let out_property =
+                self_property.and_then(move |entry| Ok(entry.is_binary(&store).block_on()?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "source_id",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|entry| describe_blob_id(&entry.source_value));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "target_id",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|entry| describe_blob_id(&entry.target_value));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "source",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -2297,6 +3904,18 @@ fn builtin_tree_entry_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "conflict_hunk_count",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let store = language.repo.store().clone();
+            let out_property = self_property.and_then(move |entry| {
+                let count = count_conflict_hunks(&store, &entry).block_on()?;
+                Ok(i64::try_from(count)?)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "file_type",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -2315,9 +3934,116 @@ fn builtin_tree_entry_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "symlink_target",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let store = language.repo.store().clone();
+            let out_property = self_property.and_then(move |entry| {
+                let Some(Some(TreeValue::Symlink(id))) = entry.value.as_resolved() else {
+                    return Ok(None);
+                };
+                let target = store.read_symlink(&entry.path, id).block_on()?;
+                Ok(Some(target))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "size",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let store = language.repo.store().clone();
+            let out_property = self_property.and_then(move |entry| {
+                let Some(Some(TreeValue::File { id, .. })) = entry.value.as_resolved() else {
+                    return Ok(None);
+                };
+                let size = diff_util::read_file_size(&store, &entry.path, id).block_on()?;
+                Ok(Some(i64::try_from(size)?))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "content",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let ([], [max_bytes_node]) = function.expect_arguments()?;
+            let max_bytes_property = max_bytes_node
+                .map(|node| {
+                    template_builder::expect_usize_expression(
+                        language,
+                        diagnostics,
+                        build_ctx,
+                        node,
+                    )
+                })
+                .transpose()?;
+            let store = language.repo.store().clone();
+            let out_property =
+                (self_property, max_bytes_property).and_then(move |(entry, max_bytes)| {
+                    let limit = max_bytes.unwrap_or(usize::MAX);
+                    let content = match entry.value.as_resolved() {
+                        Some(Some(TreeValue::File { id, .. })) => {
+                            diff_util::read_file_prefix(&store, &entry.path, id, limit)
+                                .block_on()?
+                        }
+                        Some(Some(TreeValue::Symlink(id))) => {
+                            let target = store.read_symlink(&entry.path, id).block_on()?;
+                            let mut target = target.into_bytes();
+                            target.truncate(limit);
+                            target
+                        }
+                        _ => vec![],
+                    };
+                    Ok(BString::from(content))
+                });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "content_preview",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [max_bytes_node] = function.expect_exact_arguments()?;
+            let max_bytes_property = template_builder::expect_usize_expression(
+                language,
+                diagnostics,
+                build_ctx,
+                max_bytes_node,
+            )?;
+            let store = language.repo.store().clone();
+            let out_property =
+                (self_property, max_bytes_property).and_then(move |(entry, max_bytes)| {
+                    let Some(Some(TreeValue::File { id, .. })) = entry.value.as_resolved() else {
+                        return Ok(String::new());
+                    };
+                    let preview =
+                        diff_util::read_text_file_preview(&store, &entry.path, id, max_bytes)
+                            .block_on()?;
+                    Ok(preview.unwrap_or_default())
+                });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
+/// Counts conflicted regions in the file content. Returns 0 if the entry is
+/// resolved or not a file conflict.
+async fn count_conflict_hunks(store: &Store, entry: &TreeEntry) -> BackendResult<usize> {
+    if entry.value.is_resolved() {
+        return Ok(0);
+    }
+    let materialized =
+        conflicts::materialize_tree_value(store, &entry.path, entry.value.clone()).await?;
+    let MaterializedTreeValue::FileConflict(file) = materialized else {
+        return Ok(0);
+    };
+    match files::merge_hunks(&file.contents) {
+        MergeResult::Resolved(_) => Ok(0),
+        MergeResult::Conflict(hunks) => Ok(hunks.iter().filter(|hunk| !hunk.is_resolved()).count()),
+    }
+}
+
 fn describe_file_type(value: &MergedTreeValue) -> &'static str {
     match value.as_resolved() {
         Some(Some(TreeValue::File { .. })) => "file",
@@ -2334,12 +4060,120 @@ fn is_executable_file(value: &MergedTreeValue) -> Option<bool> {
     conflicts::resolve_file_executable(&executable)
 }
 
+/// Returns hex id of the file or symlink content. Empty if the value is absent
+/// or has no content blob.
+fn describe_blob_id(value: &MergedTreeValue) -> Result<String, TemplatePropertyError> {
+    match value.as_resolved() {
+        Some(Some(TreeValue::File { id, .. })) => Ok(id.hex()),
+        Some(Some(TreeValue::Symlink(id))) => Ok(id.hex()),
+        Some(Some(TreeValue::Tree(_) | TreeValue::GitSubmodule(_)) | None) => Ok(String::new()),
+        None | Some(Some(TreeValue::Conflict(_))) => Err(TemplatePropertyError(
+            "Cannot get blob id of conflicted file".into(),
+        )),
+    }
+}
+
+/// Counts the added and removed lines compared to the parents.
+fn count_churn(
+    repo: &dyn Repo,
+    commit: &Commit,
+    conflict_marker_style: ConflictMarkerStyle,
+) -> BackendResult<usize> {
+    let matcher: Rc<dyn Matcher> = Rc::new(EverythingMatcher);
+    let diff = TreeDiff::from_commit(repo, commit, matcher, true)?;
+    let store = diff.from_tree.store();
+    // No user configuration exists for diff stat.
+    let options = diff_util::DiffStatOptions::default();
+    let stats = DiffStats::calculate(store, diff.diff_stream(), &options, conflict_marker_style)
+        .block_on()?;
+    Ok(stats.count_total_added() + stats.count_total_removed())
+}
+
+/// Returns true if the first parent has conflicts, and none of the conflicted
+/// paths are conflicted in the commit.
+fn is_conflict_resolution(commit: &Commit) -> BackendResult<bool> {
+    let Some(parent) = commit.parents().next().transpose()? else {
+        return Ok(false);
+    };
+    if !parent.has_conflict()? {
+        return Ok(false);
+    }
+    let tree = commit.tree()?;
+    for (path, _value) in parent.tree()?.conflicts() {
+        if !tree.path_value(&path)?.is_resolved() {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Kind of conflict reported by `commit.conflict_types()`, in display order.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum ConflictType {
+    Content,
+    AddAdd,
+    ModifyDelete,
+    Executable,
+    Other,
+}
+
+impl ConflictType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Content => "content",
+            Self::AddAdd => "add/add",
+            Self::ModifyDelete => "modify/delete",
+            Self::Executable => "executable",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Returns distinct kinds of conflicts in the tree.
+fn describe_conflict_types(tree: &MergedTree) -> BackendResult<Vec<String>> {
+    let mut found = BTreeSet::new();
+    for (_path, value) in tree.conflicts() {
+        let value = value?;
+        let Some(file_ids) = value.to_file_merge() else {
+            found.insert(ConflictType::Other);
+            continue;
+        };
+        if file_ids.adds().any(Option::is_none) {
+            found.insert(ConflictType::ModifyDelete);
+            continue;
+        }
+        if file_ids.resolve_trivial().is_none() {
+            if file_ids.removes().all(Option::is_none) {
+                found.insert(ConflictType::AddAdd);
+            } else {
+                found.insert(ConflictType::Content);
+            }
+        }
+        if is_executable_file(&value).is_none() {
+            found.insert(ConflictType::Executable);
+        }
+    }
+    Ok(found
+        .into_iter()
+        .map(|kind| kind.as_str().to_owned())
+        .collect())
+}
+
+fn parse_diff_stat_bar_chars(chars: &str) -> Option<DiffStatBarChars> {
+    let (added, removed) = chars.chars().collect_tuple()?;
+    [added, removed]
+        .iter()
+        .all(|c| c.width() == Some(1))
+        .then_some(DiffStatBarChars { added, removed })
+}
+
 /// [`DiffStats`] with rendering parameters.
 #[derive(Clone, Debug)]
 pub struct DiffStatsFormatted<'a> {
     stats: DiffStats,
     path_converter: &'a RepoPathUiConverter,
     width: usize,
+    bar_chars: DiffStatBarChars,
 }
 
 impl Template for DiffStatsFormatted<'_> {
@@ -2349,6 +4183,7 @@ impl Template for DiffStatsFormatted<'_> {
             &self.stats,
             self.path_converter,
             self.width,
+            self.bar_chars,
         )
     }
 }
@@ -2357,7 +4192,14 @@ fn builtin_diff_stats_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
     // Not using maplit::hashmap!{} or custom declarative macro here because
     // code completion inside macro is quite restricted.
     let mut map = CommitTemplateBuildMethodFnMap::<DiffStats>::new();
-    // TODO: add files() -> List<DiffStatEntry> ?
+    map.insert(
+        "files",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|stats| stats.entries().to_vec());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "total_added",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -2379,6 +4221,38 @@ fn builtin_diff_stats_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
     map
 }
 
+fn builtin_diff_stat_entry_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, DiffStatEntry>
+{
+    // Not using maplit::hashmap!{} or custom declarative macro here because
+    // code completion inside macro is quite restricted.
+    let mut map = CommitTemplateBuildMethodFnMap::<DiffStatEntry>::new();
+    map.insert(
+        "path",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|entry| entry.path.target);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "total_added",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|entry| Ok(i64::try_from(entry.added)?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "total_removed",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|entry| Ok(i64::try_from(entry.removed)?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map
+}
+
 #[derive(Debug)]
 pub struct CryptographicSignature {
     commit: Commit,
@@ -2411,6 +4285,16 @@ impl CryptographicSignature {
         self.verify()
             .map(|verification| verification.display.unwrap_or_default())
     }
+
+    /// Defaults to empty string if fingerprint is not present.
+    fn key_fingerprint(&self) -> SignResult<String> {
+        self.verify()
+            .map(|verification| verification.fingerprint.unwrap_or_default())
+    }
+
+    fn created(&self) -> SignResult<Option<Timestamp>> {
+        self.verify().map(|verification| verification.created)
+    }
 }
 
 fn builtin_cryptographic_signature_methods<'repo>()
@@ -2446,6 +4330,22 @@ fn builtin_cryptographic_signature_methods<'repo>()
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "key_fingerprint",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|sig| Ok(sig.key_fingerprint()?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "created",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|sig| Ok(sig.created()?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
@@ -2475,7 +4375,6 @@ fn builtin_annotation_line_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'r
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
             let out_property = self_property.map(|line| line.content);
-            // TODO: Add Bytes or BString template type?
             Ok(P::wrap_template(out_property.into_template()))
         },
     );
@@ -2541,6 +4440,7 @@ fn builtin_trailer_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Tra
 }
 
 fn builtin_trailer_list_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Vec<Trailer>> {
+    type P<'repo> = CommitTemplatePropertyKind<'repo>;
     let mut map: CommitTemplateBuildMethodFnMap<Vec<Trailer>> =
         template_builder::builtin_formattable_list_methods();
     map.insert(
@@ -2554,9 +4454,177 @@ fn builtin_trailer_list_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "format_with",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [pattern_node, lambda_node] = function.expect_exact_arguments()?;
+            let pattern = expect_string_pattern_literal(diagnostics, pattern_node)?;
+            let template = template_builder::build_partial_map_template(
+                language,
+                diagnostics,
+                build_ctx,
+                self_property,
+                Literal("\n"), // separator
+                lambda_node,
+                move |trailer: &Trailer| pattern.is_match(&trailer.key),
+            )?;
+            Ok(P::wrap_list_template(template))
+        },
+    );
+    map.insert(
+        "format_canonical",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|trailers| {
+                trailers
+                    .iter()
+                    .map(|t| format!("{}: {}\n", trailer::normalize_key(&t.key), t.value))
+                    .collect::<String>()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map
+}
+
+/// Header field of the underlying Git commit object.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GitHeader {
+    pub key: String,
+    pub value: String,
+}
+
+impl Template for GitHeader {
+    fn format(&self, formatter: &mut TemplateFormatter) -> io::Result<()> {
+        write!(formatter, "{} {}", self.key, self.value)
+    }
+}
+
+impl Template for Vec<GitHeader> {
+    fn format(&self, formatter: &mut TemplateFormatter) -> io::Result<()> {
+        templater::format_joined(formatter, self, "\n")
+    }
+}
+
+fn builtin_git_header_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, GitHeader> {
+    let mut map = CommitTemplateBuildMethodFnMap::<GitHeader>::new();
+    map.insert(
+        "key",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|header| header.key);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "value",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|header| header.value);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
+/// Looks up the fetch URLs of the Git remotes. Credentials embedded in the
+/// URLs are masked.
+///
+/// Returns an empty map if the repo isn't backed by Git.
+#[cfg(feature = "git")]
+fn load_git_remote_urls(
+    store: &Store,
+) -> Result<HashMap<String, String>, Box<dyn std::error::Error + Send + Sync>> {
+    let Ok(git_repo) = jj_lib::git::get_git_repo(store) else {
+        return Ok(HashMap::new());
+    };
+    let mut urls = HashMap::new();
+    for name in git_repo.remote_names() {
+        // Skip empty [remote "<name>"] section
+        let Some(remote) = git_repo.try_find_remote(name.as_ref()) else {
+            continue;
+        };
+        if let Some(url) = remote?.url(gix::remote::Direction::Fetch) {
+            let url = url.to_bstring().to_string();
+            let url = crate::git_util::redact_url_credentials(&url).into_owned();
+            urls.insert(name.to_string(), url);
+        }
+    }
+    Ok(urls)
+}
+
+#[cfg(not(feature = "git"))]
+fn load_git_remote_urls(
+    _store: &Store,
+) -> Result<HashMap<String, String>, Box<dyn std::error::Error + Send + Sync>> {
+    Ok(HashMap::new())
+}
+
+/// Reads the raw Git commit object backing the given commit.
+///
+/// Returns `None` if the repo isn't backed by Git.
+#[cfg(feature = "git")]
+fn read_git_commit_object(
+    store: &Store,
+    commit: &Commit,
+) -> Result<Option<Vec<u8>>, TemplatePropertyError> {
+    let Ok(git_repo) = jj_lib::git::get_git_repo(store) else {
+        return Ok(None);
+    };
+    if commit.id() == store.root_commit_id() {
+        return Ok(None);
+    }
+    let oid = gix::ObjectId::from_bytes_or_panic(commit.id().as_bytes());
+    let object = git_repo.find_object(oid)?;
+    Ok(Some(object.detach().data))
+}
+
+#[cfg(not(feature = "git"))]
+fn read_git_commit_object(
+    _store: &Store,
+    _commit: &Commit,
+) -> Result<Option<Vec<u8>>, TemplatePropertyError> {
+    Ok(None)
+}
+
+/// Reads header fields of the Git commit object backing the given commit.
+///
+/// Returns an empty list if the repo isn't backed by Git.
+fn read_git_headers(
+    store: &Store,
+    commit: &Commit,
+) -> Result<Vec<GitHeader>, TemplatePropertyError> {
+    let Some(data) = read_git_commit_object(store, commit)? else {
+        return Ok(vec![]);
+    };
+    Ok(parse_git_headers(&data))
+}
+
+/// Parses header lines of a raw Git commit object. Continuation lines (which
+/// start with a space) are appended to the value of the preceding header.
+fn parse_git_headers(data: &[u8]) -> Vec<GitHeader> {
+    let mut headers: Vec<GitHeader> = vec![];
+    for line in data.split(|&b| b == b'\n') {
+        if line.is_empty() {
+            break;
+        }
+        let line = String::from_utf8_lossy(line);
+        if let Some(continuation) = line.strip_prefix(' ') {
+            if let Some(header) = headers.last_mut() {
+                header.value.push('\n');
+                header.value.push_str(continuation);
+            }
+            continue;
+        }
+        let (key, value) = line.split_once(' ').unwrap_or((&line, ""));
+        headers.push(GitHeader {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        });
+    }
+    headers
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -2594,6 +4662,7 @@ mod tests {
         template_aliases_map: TemplateAliasesMap,
         immutable_expression: Rc<UserRevsetExpression>,
         extra_functions: HashMap<&'static str, BuildFunctionFn>,
+        extensions: Vec<Box<dyn CommitTemplateLanguageExtension>>,
     }
 
     impl CommitTemplateTestEnv {
@@ -2619,6 +4688,7 @@ mod tests {
                 template_aliases_map: TemplateAliasesMap::new(),
                 immutable_expression: RevsetExpression::none(),
                 extra_functions: HashMap::new(),
+                extensions: vec![],
             }
         }
 
@@ -2633,6 +4703,10 @@ mod tests {
             self.extra_functions.insert(name, f);
         }
 
+        fn add_extension(&mut self, extension: impl CommitTemplateLanguageExtension + 'static) {
+            self.extensions.push(Box::new(extension));
+        }
+
         fn new_language(&self) -> CommitTemplateLanguage<'_> {
             let revset_parse_context = RevsetParseContext {
                 aliases_map: &self.revset_aliases_map,
@@ -2653,7 +4727,8 @@ mod tests {
                 &self.id_prefix_context,
                 self.immutable_expression.clone(),
                 ConflictMarkerStyle::default(),
-                &[] as &[Box<dyn CommitTemplateLanguageExtension>],
+                80,
+                &self.extensions,
             );
             // Not using .extend() to infer lifetime of f
             for (&name, &f) in &self.extra_functions {
@@ -2769,6 +4844,11 @@ mod tests {
         insta::assert_snapshot!(env.render_ok(template, &repo_path_buf("file")), @"");
         insta::assert_snapshot!(env.render_ok(template, &repo_path_buf("dir/file")), @"dir");
 
+        insta::assert_snapshot!(env.render_ok("self.depth()", &repo_path_buf("")), @"0");
+        insta::assert_snapshot!(env.render_ok("self.depth()", &repo_path_buf("file")), @"1");
+        insta::assert_snapshot!(
+            env.render_ok("self.depth()", &repo_path_buf("a/b/c/d/file")), @"5");
+
         // JSON
         insta::assert_snapshot!(
             env.render_ok("json(self)", &repo_path_buf("dir/file")), @r#""dir/file""#);
@@ -2851,8 +4931,96 @@ mod tests {
             rest: "3abcdef".to_owned(),
         };
 
+        insta::assert_snapshot!(
+            env.render_ok(r#"self.prefix() ++ "[" ++ self.rest() ++ "]""#, &id),
+            @"012[3abcdef]");
+        insta::assert_snapshot!(
+            env.render_ok(r#"self.highlighted("[", "]")"#, &id), @"012[3abcdef]");
+        insta::assert_snapshot!(
+            env.render_ok(r#"self.upper().highlighted("<", ">")"#, &id), @"012<3ABCDEF>");
+        insta::assert_snapshot!(env.render_ok("self.len()", &id), @"10");
+        insta::assert_snapshot!(
+            env.render_ok("self.len() == self.prefix().len() + self.rest().len()", &id),
+            @"true");
+
         // JSON
         insta::assert_snapshot!(
             env.render_ok("json(self)", &id), @r#"{"prefix":"012","rest":"3abcdef"}"#);
     }
+
+    #[test]
+    fn test_commit_method_from_extension() {
+        // Resolves issue references in descriptions to canned statuses.
+        struct IssueStatuses(HashMap<&'static str, &'static str>);
+
+        struct IssueTracker;
+
+        impl CommitTemplateLanguageExtension for IssueTracker {
+            fn build_fn_table<'repo>(&self) -> CommitTemplateBuildFnTable<'repo> {
+                let mut table = CommitTemplateBuildFnTable::empty();
+                table.commit_methods.insert(
+                    "enrich",
+                    |language, diagnostics, _build_ctx, self_property, function| {
+                        let [field_node] = function.expect_exact_arguments()?;
+                        template_parser::catch_aliases(diagnostics, field_node, |_, node| {
+                            match template_parser::expect_string_literal(node)? {
+                                "status" => Ok(()),
+                                _ => Err(TemplateParseError::expression(
+                                    "Expected \"status\"",
+                                    node.span,
+                                )),
+                            }
+                        })?;
+                        let statuses = language
+                            .cache_extension::<IssueStatuses>()
+                            .unwrap()
+                            .0
+                            .clone();
+                        let out_property = self_property.map(move |commit| {
+                            commit
+                                .description()
+                                .split(|c: char| !c.is_ascii_alphanumeric() && c != '#')
+                                .filter_map(|word| statuses.get(word))
+                                .next()
+                                .map_or_else(String::new, |&status| status.to_owned())
+                        });
+                        Ok(out_property.into_dyn_wrapped())
+                    },
+                );
+                table
+            }
+
+            fn build_cache_extensions(&self, extensions: &mut ExtensionsMap) {
+                extensions.insert(IssueStatuses(HashMap::from([("#123", "closed")])));
+            }
+        }
+
+        let mut env = CommitTemplateTestEnv::init();
+        env.add_extension(IssueTracker);
+
+        let mut tx = env.test_workspace.repo.start_transaction();
+        let mut new_commit = |description: &str| {
+            testutils::create_random_commit(tx.repo_mut())
+                .set_description(description)
+                .write()
+                .unwrap()
+        };
+        let fixed = new_commit("Fix crash (#123)\n");
+        let other = new_commit("Refer to #456\n");
+        let plain = new_commit("No issue\n");
+
+        insta::assert_snapshot!(env.render_ok(r#"self.enrich("status")"#, &fixed), @"closed");
+        insta::assert_snapshot!(env.render_ok(r#"self.enrich("status")"#, &other), @"");
+        insta::assert_snapshot!(env.render_ok(r#"self.enrich("status")"#, &plain), @"");
+        insta::assert_snapshot!(
+            env.parse(r#"self.enrich("title")"#).map(|_: TemplateRenderer<Commit>| ()).unwrap_err(),
+            @r#"
+         --> 1:13
+          |
+        1 | self.enrich("title")
+          |             ^-----^
+          |
+          = Expected "status"
+        "#);
+    }
 }
@@ -23,6 +23,7 @@ use std::path::PathBuf;
 
 use bstr::BStr;
 use bstr::BString;
+use bstr::ByteSlice as _;
 use clap_complete::ArgValueCandidates;
 use futures::StreamExt as _;
 use futures::TryStreamExt as _;
@@ -33,6 +34,7 @@ use jj_lib::backend::BackendError;
 use jj_lib::backend::BackendResult;
 use jj_lib::backend::CommitId;
 use jj_lib::backend::CopyRecord;
+use jj_lib::backend::FileId;
 use jj_lib::backend::TreeValue;
 use jj_lib::commit::Commit;
 use jj_lib::config::ConfigGetError;
@@ -72,6 +74,7 @@ use jj_lib::settings::UserSettings;
 use jj_lib::store::Store;
 use pollster::FutureExt as _;
 use thiserror::Error;
+use tokio::io::AsyncReadExt as _;
 use tracing::instrument;
 use unicode_width::UnicodeWidthStr as _;
 
@@ -474,7 +477,8 @@ impl<'a> DiffRenderer<'a> {
                     let stats =
                         DiffStats::calculate(store, tree_diff, options, self.conflict_marker_style)
                             .block_on()?;
-                    show_diff_stats(formatter, &stats, path_converter, width)?;
+                    let bar_chars = DiffStatBarChars::default();
+                    show_diff_stats(formatter, &stats, path_converter, width, bar_chars)?;
                 }
                 DiffFormat::Types => {
                     let tree_diff =
@@ -1169,22 +1173,98 @@ impl FileContent<Merge<BString>> {
     }
 }
 
+/// Number of leading bytes inspected to determine whether a file is binary.
+const BINARY_PEEK_SIZE: usize = 8000;
+
+/// Returns true if the file contents are likely binary.
+///
+/// Determine whether it's binary by whether the first 8k bytes contain a null
+/// character; this is the same heuristic used by git as of writing: https://github.com/git/git/blob/eea0e59ffbed6e33d171ace5be13cde9faa41639/xdiff-interface.c#L192-L198
+pub fn is_binary_content(contents: &[u8]) -> bool {
+    contents[..BINARY_PEEK_SIZE.min(contents.len())].contains(&b'\0')
+}
+
+/// Reads up to `limit` bytes from the beginning of the file.
+pub async fn read_file_prefix(
+    store: &Store,
+    path: &RepoPath,
+    id: &FileId,
+    limit: usize,
+) -> BackendResult<Vec<u8>> {
+    let mut buf = Vec::new();
+    store
+        .read_file(path, id)
+        .await?
+        .take(limit as u64)
+        .read_to_end(&mut buf)
+        .await
+        .map_err(|err| BackendError::ReadFile {
+            path: path.to_owned(),
+            id: id.clone(),
+            source: err.into(),
+        })?;
+    Ok(buf)
+}
+
+/// Returns the size of the file content in bytes.
+///
+/// The backends don't provide a way to look up the size, so the whole content
+/// is read.
+pub async fn read_file_size(store: &Store, path: &RepoPath, id: &FileId) -> BackendResult<u64> {
+    let mut reader = store.read_file(path, id).await?;
+    tokio::io::copy(&mut reader, &mut tokio::io::sink())
+        .await
+        .map_err(|err| BackendError::ReadFile {
+            path: path.to_owned(),
+            id: id.clone(),
+            source: err.into(),
+        })
+}
+
+/// Reads the leading bytes of the file, and returns true if it is likely
+/// binary.
+pub async fn is_binary_file(store: &Store, path: &RepoPath, id: &FileId) -> BackendResult<bool> {
+    let start = read_file_prefix(store, path, id, BINARY_PEEK_SIZE).await?;
+    Ok(is_binary_content(&start))
+}
+
+/// Reads up to `max_bytes` of the file as text. Returns `None` if the file is
+/// likely binary.
+///
+/// The content is truncated at UTF-8 character boundary, and invalid UTF-8
+/// sequences are replaced.
+pub async fn read_text_file_preview(
+    store: &Store,
+    path: &RepoPath,
+    id: &FileId,
+    max_bytes: usize,
+) -> BackendResult<Option<String>> {
+    let mut buf = read_file_prefix(store, path, id, max_bytes.max(BINARY_PEEK_SIZE)).await?;
+    if is_binary_content(&buf) {
+        return Ok(None);
+    }
+    buf.truncate(max_bytes);
+    // Drop incomplete character at the end
+    if let Err(err) = std::str::from_utf8(&buf) {
+        if err.error_len().is_none() {
+            buf.truncate(err.valid_up_to());
+        }
+    }
+    Ok(Some(String::from_utf8_lossy(&buf).into_owned()))
+}
+
 fn file_content_for_diff<T>(
     path: &RepoPath,
     file: &mut MaterializedFileValue,
     map_resolved: impl FnOnce(BString) -> T,
 ) -> BackendResult<FileContent<T>> {
     // If this is a binary file, don't show the full contents.
-    // Determine whether it's binary by whether the first 8k bytes contain a null
-    // character; this is the same heuristic used by git as of writing: https://github.com/git/git/blob/eea0e59ffbed6e33d171ace5be13cde9faa41639/xdiff-interface.c#L192-L198
-    const PEEK_SIZE: usize = 8000;
     // TODO: currently we look at the whole file, even though for binary files we
     // only need to know the file size. To change that we'd have to extend all
     // the data backends to support getting the length.
     let contents = BString::new(file.read_all(path).block_on()?);
-    let start = &contents[..PEEK_SIZE.min(contents.len())];
     Ok(FileContent {
-        is_binary: start.contains(&b'\0'),
+        is_binary: is_binary_content(&contents),
         contents: map_resolved(contents),
     })
 }
@@ -1490,7 +1570,8 @@ pub async fn show_file_by_file_diff(
 struct GitDiffPart {
     /// Octal mode string or `None` if the file is absent.
     mode: Option<&'static str>,
-    hash: String,
+    /// Full hex hash or `None` if the content has no associated object.
+    hash: Option<String>,
     content: FileContent<BString>,
 }
 
@@ -1499,15 +1580,14 @@ fn git_diff_part(
     value: MaterializedTreeValue,
     conflict_marker_style: ConflictMarkerStyle,
 ) -> Result<GitDiffPart, DiffRenderError> {
-    const DUMMY_HASH: &str = "0000000000";
     let mode;
-    let mut hash;
+    let hash;
     let content;
     match value {
         MaterializedTreeValue::Absent => {
             return Ok(GitDiffPart {
                 mode: None,
-                hash: DUMMY_HASH.to_owned(),
+                hash: None,
                 content: FileContent {
                     is_binary: false,
                     contents: BString::default(),
@@ -1522,12 +1602,12 @@ fn git_diff_part(
         }
         MaterializedTreeValue::File(mut file) => {
             mode = if file.executable { "100755" } else { "100644" };
-            hash = file.id.hex();
+            hash = Some(file.id.hex());
             content = file_content_for_diff(path, &mut file, |content| content)?;
         }
         MaterializedTreeValue::Symlink { id, target } => {
             mode = "120000";
-            hash = id.hex();
+            hash = Some(id.hex());
             content = FileContent {
                 // Unix file paths can't contain null bytes.
                 is_binary: false,
@@ -1537,7 +1617,7 @@ fn git_diff_part(
         MaterializedTreeValue::GitSubmodule(id) => {
             // TODO: What should we actually do here?
             mode = "040000";
-            hash = id.hex();
+            hash = Some(id.hex());
             content = FileContent {
                 is_binary: false,
                 contents: BString::default(),
@@ -1548,7 +1628,7 @@ fn git_diff_part(
                 Some(true) => "100755",
                 Some(false) | None => "100644",
             };
-            hash = DUMMY_HASH.to_owned();
+            hash = None;
             content = FileContent {
                 is_binary: false, // TODO: are we sure this is never binary?
                 contents: materialize_merge_result_to_bytes(&file.contents, conflict_marker_style),
@@ -1556,7 +1636,7 @@ fn git_diff_part(
         }
         MaterializedTreeValue::OtherConflict { id } => {
             mode = "100644";
-            hash = DUMMY_HASH.to_owned();
+            hash = None;
             content = FileContent {
                 is_binary: false,
                 contents: id.describe().into(),
@@ -1566,7 +1646,6 @@ fn git_diff_part(
             panic!("Unexpected tree in diff at path {path:?}");
         }
     }
-    hash.truncate(10);
     Ok(GitDiffPart {
         mode: Some(mode),
         hash,
@@ -1574,12 +1653,18 @@ fn git_diff_part(
     })
 }
 
+/// Default number of hex digits of object hashes in `index` lines.
+pub const DEFAULT_INDEX_HASH_LEN: usize = 10;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UnifiedDiffOptions {
     /// Number of context lines to show.
     pub context: usize,
     /// How lines are tokenized and compared.
     pub line_diff: LineDiffOptions,
+    /// Number of hex digits of object hashes to show in `index` lines, or
+    /// `None` to show full hashes.
+    pub index_hash_len: Option<usize>,
 }
 
 impl UnifiedDiffOptions {
@@ -1587,6 +1672,7 @@ impl UnifiedDiffOptions {
         Ok(Self {
             context: settings.get("diff.git.context")?,
             line_diff: LineDiffOptions::default(),
+            index_hash_len: Some(DEFAULT_INDEX_HASH_LEN),
         })
     }
 
@@ -1822,6 +1908,27 @@ fn show_diff_line_tokens(
     Ok(())
 }
 
+/// Formats `index` line hashes of both sides abbreviated to `hash_len`. Sides
+/// without an object hash are filled with zeros.
+fn format_index_hashes(
+    left_part: &GitDiffPart,
+    right_part: &GitDiffPart,
+    hash_len: Option<usize>,
+) -> (String, String) {
+    // Git object ids are 40 hex digits. Use it if neither side has an id.
+    let full_len = left_part
+        .hash
+        .as_ref()
+        .or(right_part.hash.as_ref())
+        .map_or(40, |hash| hash.len());
+    let len = hash_len.map_or(full_len, |len| len.min(full_len));
+    let format = |hash: &Option<String>| match hash {
+        Some(hash) => hash[..len.min(hash.len())].to_owned(),
+        None => "0".repeat(len),
+    };
+    (format(&left_part.hash), format(&right_part.hash))
+}
+
 pub async fn show_git_diff(
     formatter: &mut dyn Formatter,
     store: &Store,
@@ -1845,8 +1952,8 @@ pub async fn show_git_diff(
                 formatter,
                 "diff --git a/{left_path_string} b/{right_path_string}"
             )?;
-            let left_hash = &left_part.hash;
-            let right_hash = &right_part.hash;
+            let (left_hash, right_hash) =
+                format_index_hashes(&left_part, &right_part, options.index_hash_len);
             match (left_part.mode, right_part.mode) {
                 (None, Some(right_mode)) => {
                     writeln!(formatter, "new file mode {right_mode}")?;
@@ -1966,6 +2073,22 @@ impl DiffStatOptions {
     }
 }
 
+/// Characters to draw the bar of changed lines in diff stat output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DiffStatBarChars {
+    pub added: char,
+    pub removed: char,
+}
+
+impl Default for DiffStatBarChars {
+    fn default() -> Self {
+        Self {
+            added: '+',
+            removed: '-',
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct DiffStats {
     entries: Vec<DiffStatEntry>,
@@ -2047,11 +2170,78 @@ fn get_diff_stat_entry(
     }
 }
 
+/// Added and removed lines across all files.
+#[derive(Clone, Debug, Default)]
+pub struct DiffLines {
+    /// Added lines without line terminators.
+    pub added: Vec<BString>,
+    /// Removed lines without line terminators.
+    pub removed: Vec<BString>,
+}
+
+impl DiffLines {
+    /// Collects changed lines of text files. Binary files are skipped.
+    pub async fn calculate(
+        store: &Store,
+        tree_diff: BoxStream<'_, CopiesTreeDiffEntry>,
+        options: &LineDiffOptions,
+        conflict_marker_style: ConflictMarkerStyle,
+    ) -> BackendResult<Self> {
+        let mut lines = Self::default();
+        let mut diff_stream = materialized_diff_stream(store, tree_diff);
+        while let Some(MaterializedTreeDiffEntry { path, values }) = diff_stream.next().await {
+            let (left, right) = values?;
+            let left_content = diff_content(path.source(), left, conflict_marker_style)?;
+            let right_content = diff_content(path.target(), right, conflict_marker_style)?;
+            if left_content.is_binary || right_content.is_binary {
+                continue;
+            }
+            let contents = [&left_content.contents, &right_content.contents];
+            let diff = diff_by_line(contents, options);
+            for hunk in diff.hunks() {
+                match hunk.kind {
+                    DiffHunkKind::Matching => {}
+                    DiffHunkKind::Different => {
+                        let [left, right] = hunk.contents[..].try_into().unwrap();
+                        lines.removed.extend(left.lines().map(BString::from));
+                        lines.added.extend(right.lines().map(BString::from));
+                    }
+                }
+            }
+        }
+        Ok(lines)
+    }
+}
+
+/// Counts hunks of the unified diff across all files. Binary files are
+/// skipped.
+pub async fn count_unified_diff_hunks(
+    store: &Store,
+    tree_diff: BoxStream<'_, CopiesTreeDiffEntry>,
+    options: &UnifiedDiffOptions,
+    conflict_marker_style: ConflictMarkerStyle,
+) -> BackendResult<usize> {
+    let mut num_hunks = 0;
+    let mut diff_stream = materialized_diff_stream(store, tree_diff);
+    while let Some(MaterializedTreeDiffEntry { path, values }) = diff_stream.next().await {
+        let (left, right) = values?;
+        let left_content = diff_content(path.source(), left, conflict_marker_style)?;
+        let right_content = diff_content(path.target(), right, conflict_marker_style)?;
+        if left_content.is_binary || right_content.is_binary {
+            continue;
+        }
+        let contents = [&left_content.contents, &right_content.contents].map(BStr::new);
+        num_hunks += unified_diff_hunks(contents, options).len();
+    }
+    Ok(num_hunks)
+}
+
 pub fn show_diff_stats(
     formatter: &mut dyn Formatter,
     stats: &DiffStats,
     path_converter: &RepoPathUiConverter,
     display_width: usize,
+    bar_chars: DiffStatBarChars,
 ) -> io::Result<()> {
     let ui_paths = stats
         .entries()
@@ -2119,8 +2309,17 @@ pub fn show_diff_stats(
             stat.added + stat.removed,
             if bar_added + bar_removed > 0 { " " } else { "" },
         )?;
-        write!(formatter.labeled("added"), "{}", "+".repeat(bar_added))?;
-        writeln!(formatter.labeled("removed"), "{}", "-".repeat(bar_removed))?;
+        let added = iter::repeat_n(bar_chars.added, bar_added);
+        let removed = iter::repeat_n(bar_chars.removed, bar_removed);
+        // Write the newline outside the labels so that an empty bar doesn't
+        // produce an empty colored span.
+        write!(formatter.labeled("added"), "{}", added.collect::<String>())?;
+        write!(
+            formatter.labeled("removed"),
+            "{}",
+            removed.collect::<String>()
+        )?;
+        writeln!(formatter)?;
     }
 
     let total_added = stats.count_total_added();
@@ -41,6 +41,11 @@ pub trait Formatter: Write {
     /// already formatted, such as in the graphical log.
     fn raw(&mut self) -> io::Result<Box<dyn Write + '_>>;
 
+    /// Returns the backing `Write` if the output supports terminal escape
+    /// sequences other than colors, such as hyperlinks. Returns `None` if
+    /// such escape sequences should be omitted.
+    fn raw_if_color(&mut self) -> io::Result<Option<Box<dyn Write + '_>>>;
+
     fn push_label(&mut self, label: &str) -> io::Result<()>;
 
     fn pop_label(&mut self) -> io::Result<()>;
@@ -212,6 +217,10 @@ impl<W: Write> Formatter for PlainTextFormatter<W> {
         Ok(Box::new(self.output.by_ref()))
     }
 
+    fn raw_if_color(&mut self) -> io::Result<Option<Box<dyn Write + '_>>> {
+        Ok(None)
+    }
+
     fn push_label(&mut self, _label: &str) -> io::Result<()> {
         Ok(())
     }
@@ -247,6 +256,10 @@ impl<W: Write> Formatter for SanitizingFormatter<W> {
         Ok(Box::new(self.output.by_ref()))
     }
 
+    fn raw_if_color(&mut self) -> io::Result<Option<Box<dyn Write + '_>>> {
+        Ok(None)
+    }
+
     fn push_label(&mut self, _label: &str) -> io::Result<()> {
         Ok(())
     }
@@ -580,6 +593,10 @@ impl<W: Write> Formatter for ColorFormatter<W> {
         Ok(Box::new(self.output.by_ref()))
     }
 
+    fn raw_if_color(&mut self) -> io::Result<Option<Box<dyn Write + '_>>> {
+        self.raw().map(Some)
+    }
+
     fn push_label(&mut self, label: &str) -> io::Result<()> {
         self.labels.push(label.to_owned());
         Ok(())
@@ -621,6 +638,8 @@ enum FormatOp {
     PushLabel(String),
     PopLabel,
     RawEscapeSequence(Vec<u8>),
+    /// Escape sequence to be emitted only if the output supports it.
+    ColorEscapeSequence(Vec<u8>),
 }
 
 impl FormatRecorder {
@@ -671,6 +690,11 @@ impl FormatRecorder {
                 FormatOp::RawEscapeSequence(raw_escape_sequence) => {
                     formatter.raw()?.write_all(raw_escape_sequence)?;
                 }
+                FormatOp::ColorEscapeSequence(escape_sequence) => {
+                    if let Some(mut raw) = formatter.raw_if_color()? {
+                        raw.write_all(escape_sequence)?;
+                    }
+                }
             }
         }
         flush_data(formatter, self.data.len())
@@ -701,11 +725,29 @@ impl Write for RawEscapeSequenceRecorder<'_> {
     }
 }
 
+struct ColorEscapeSequenceRecorder<'a>(&'a mut FormatRecorder);
+
+impl Write for ColorEscapeSequenceRecorder<'_> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.0.push_op(FormatOp::ColorEscapeSequence(data.to_vec()));
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
 impl Formatter for FormatRecorder {
     fn raw(&mut self) -> io::Result<Box<dyn Write + '_>> {
         Ok(Box::new(RawEscapeSequenceRecorder(self)))
     }
 
+    fn raw_if_color(&mut self) -> io::Result<Option<Box<dyn Write + '_>>> {
+        // Whether the escape sequence is emitted is decided on replay.
+        Ok(Some(Box::new(ColorEscapeSequenceRecorder(self))))
+    }
+
     fn push_label(&mut self, label: &str) -> io::Result<()> {
         self.push_op(FormatOp::PushLabel(label.to_owned()));
         Ok(())
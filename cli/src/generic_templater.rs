@@ -15,6 +15,7 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use jj_lib::backend::Timestamp;
 use jj_lib::settings::UserSettings;
 
 use crate::template_builder;
@@ -191,6 +192,20 @@ impl<'a, C> CoreTemplatePropertyVar<'a> for GenericTemplatePropertyKind<'a, C> {
         }
     }
 
+    fn try_into_timestamp(self) -> Option<BoxedTemplateProperty<'a, Timestamp>> {
+        match self {
+            Self::Core(property) => property.try_into_timestamp(),
+            Self::Self_(_) => None,
+        }
+    }
+
+    fn try_into_string_list(self) -> Option<BoxedTemplateProperty<'a, Vec<String>>> {
+        match self {
+            Self::Core(property) => property.try_into_string_list(),
+            Self::Self_(_) => None,
+        }
+    }
+
     fn try_into_stringify(self) -> Option<BoxedTemplateProperty<'a, String>> {
         match self {
             Self::Core(property) => property.try_into_stringify(),
@@ -0,0 +1,198 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mapping of author/committer identities to canonical names and email
+//! addresses in Git's `.mailmap` format.
+
+use jj_lib::backend::Signature;
+
+/// Parsed `.mailmap` entries.
+#[derive(Clone, Debug, Default)]
+pub struct Mailmap {
+    entries: Vec<MailmapEntry>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct MailmapEntry {
+    proper_name: Option<String>,
+    proper_email: Option<String>,
+    commit_name: Option<String>,
+    commit_email: String,
+}
+
+impl Mailmap {
+    /// Parses the content of `.mailmap` file. Malformed lines are ignored.
+    pub fn parse(text: &str) -> Self {
+        let entries = text.lines().filter_map(parse_line).collect();
+        Self { entries }
+    }
+
+    /// Returns true if there are no mapping entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Maps the name and email of the `signature` to the canonical ones.
+    ///
+    /// Entries matching both the name and email take precedence over the ones
+    /// matching only the email. Emails and names are compared
+    /// case-insensitively.
+    pub fn resolve(&self, signature: &Signature) -> Signature {
+        let mut name = None;
+        let mut email = None;
+        let matching_entries = self
+            .entries
+            .iter()
+            .filter(|entry| entry.commit_email.eq_ignore_ascii_case(&signature.email));
+        let email_only_entries = matching_entries
+            .clone()
+            .filter(|entry| entry.commit_name.is_none());
+        let name_entries = matching_entries.filter(|entry| {
+            entry
+                .commit_name
+                .as_ref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(&signature.name))
+        });
+        // Later entries override earlier ones
+        for entry in email_only_entries.chain(name_entries) {
+            name = entry.proper_name.as_ref().or(name);
+            email = entry.proper_email.as_ref().or(email);
+        }
+        Signature {
+            name: name.unwrap_or(&signature.name).clone(),
+            email: email.unwrap_or(&signature.email).clone(),
+            timestamp: signature.timestamp,
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Option<MailmapEntry> {
+    let line = line.trim();
+    if line.starts_with('#') {
+        return None;
+    }
+    let (name1, email1, rest) = parse_name_and_email(line)?;
+    if let Some((name2, email2, _)) = parse_name_and_email(rest) {
+        // "Proper Name <proper@email> [Commit Name] <commit@email>"
+        Some(MailmapEntry {
+            proper_name: name1,
+            proper_email: Some(email1),
+            commit_name: name2,
+            commit_email: email2,
+        })
+    } else {
+        // "Proper Name <commit@email>"
+        Some(MailmapEntry {
+            proper_name: Some(name1?),
+            proper_email: None,
+            commit_name: None,
+            commit_email: email1,
+        })
+    }
+}
+
+/// Splits `"Name <email> rest"` into the optional name, email, and the rest.
+fn parse_name_and_email(text: &str) -> Option<(Option<String>, String, &str)> {
+    let (name, rest) = text.split_once('<')?;
+    let (email, rest) = rest.split_once('>')?;
+    let name = name.trim();
+    let name = (!name.is_empty()).then(|| name.to_owned());
+    Some((name, email.trim().to_owned(), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use jj_lib::backend::MillisSinceEpoch;
+    use jj_lib::backend::Timestamp;
+
+    use super::*;
+
+    fn signature(name: &str, email: &str) -> Signature {
+        Signature {
+            name: name.to_owned(),
+            email: email.to_owned(),
+            timestamp: Timestamp {
+                timestamp: MillisSinceEpoch(0),
+                tz_offset: 0,
+            },
+        }
+    }
+
+    fn resolve(mailmap: &Mailmap, name: &str, email: &str) -> (String, String) {
+        let signature = mailmap.resolve(&signature(name, email));
+        (signature.name, signature.email)
+    }
+
+    #[test]
+    fn test_parse() {
+        let mailmap = Mailmap::parse(indoc! {"
+            # comment
+
+            Proper Name <commit@example.com>
+            <proper@example.com> <commit2@example.com>
+            Proper Name <proper@example.com> Commit Name <commit3@example.com>
+            malformed line
+        "});
+        assert_eq!(
+            mailmap.entries,
+            vec![
+                MailmapEntry {
+                    proper_name: Some("Proper Name".to_owned()),
+                    proper_email: None,
+                    commit_name: None,
+                    commit_email: "commit@example.com".to_owned(),
+                },
+                MailmapEntry {
+                    proper_name: None,
+                    proper_email: Some("proper@example.com".to_owned()),
+                    commit_name: None,
+                    commit_email: "commit2@example.com".to_owned(),
+                },
+                MailmapEntry {
+                    proper_name: Some("Proper Name".to_owned()),
+                    proper_email: Some("proper@example.com".to_owned()),
+                    commit_name: Some("Commit Name".to_owned()),
+                    commit_email: "commit3@example.com".to_owned(),
+                },
+            ]
+        );
+        assert!(Mailmap::parse("# comment only\n").is_empty());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mailmap = Mailmap::parse(indoc! {"
+            Proper Name <old@example.com>
+            <new@example.com> <old@example.com>
+            Other Name <other@example.com> Some Name <shared@example.com>
+        "});
+        assert_eq!(
+            resolve(&mailmap, "Old Name", "OLD@example.com"),
+            ("Proper Name".to_owned(), "new@example.com".to_owned())
+        );
+        assert_eq!(
+            resolve(&mailmap, "some name", "shared@example.com"),
+            ("Other Name".to_owned(), "other@example.com".to_owned())
+        );
+        assert_eq!(
+            resolve(&mailmap, "Another Name", "shared@example.com"),
+            ("Another Name".to_owned(), "shared@example.com".to_owned())
+        );
+        assert_eq!(
+            resolve(&mailmap, "Unknown", "unknown@example.com"),
+            ("Unknown".to_owned(), "unknown@example.com".to_owned())
+        );
+    }
+}
@@ -18,6 +18,7 @@ use std::collections::HashMap;
 use std::io;
 
 use itertools::Itertools as _;
+use jj_lib::backend::Timestamp;
 use jj_lib::extensions_map::ExtensionsMap;
 use jj_lib::object_id::ObjectId as _;
 use jj_lib::op_store::OperationId;
@@ -189,6 +190,20 @@ impl CoreTemplatePropertyVar<'static> for OperationTemplatePropertyKind {
         }
     }
 
+    fn try_into_timestamp(self) -> Option<BoxedTemplateProperty<'static, Timestamp>> {
+        match self {
+            Self::Core(property) => property.try_into_timestamp(),
+            _ => None,
+        }
+    }
+
+    fn try_into_string_list(self) -> Option<BoxedTemplateProperty<'static, Vec<String>>> {
+        match self {
+            Self::Core(property) => property.try_into_string_list(),
+            _ => None,
+        }
+    }
+
     fn try_into_stringify(self) -> Option<BoxedTemplateProperty<'static, String>> {
         match self {
             Self::Core(property) => property.try_into_stringify(),
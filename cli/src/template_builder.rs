@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::io;
 use std::iter;
 
+use bstr::BString;
 use itertools::Itertools as _;
 use jj_lib::backend::Signature;
 use jj_lib::backend::Timestamp;
@@ -48,10 +50,13 @@ use crate::templater::CoalesceTemplate;
 use crate::templater::ConcatTemplate;
 use crate::templater::ConditionalTemplate;
 use crate::templater::Email;
+use crate::templater::HyperlinkTemplate;
+use crate::templater::Indexed;
 use crate::templater::LabelTemplate;
 use crate::templater::ListPropertyTemplate;
 use crate::templater::ListTemplate;
 use crate::templater::Literal;
+use crate::templater::Pair;
 use crate::templater::PlainTextFormattedProperty;
 use crate::templater::PropertyPlaceholder;
 use crate::templater::RawEscapeSequenceTemplate;
@@ -150,7 +155,15 @@ pub(crate) use impl_property_wrappers;
 pub trait CoreTemplatePropertyVar<'a>
 where
     Self: WrapTemplateProperty<'a, String>,
+    Self: WrapTemplateProperty<'a, Option<String>>,
     Self: WrapTemplateProperty<'a, Vec<String>>,
+    Self: WrapTemplateProperty<'a, Pair<String, String>>,
+    Self: WrapTemplateProperty<'a, Vec<Pair<String, String>>>,
+    Self: WrapTemplateProperty<'a, Pair<Vec<String>, Vec<String>>>,
+    Self: WrapTemplateProperty<'a, Pair<String, Vec<String>>>,
+    Self: WrapTemplateProperty<'a, Vec<Pair<String, Vec<String>>>>,
+    Self: WrapTemplateProperty<'a, Indexed<String>>,
+    Self: WrapTemplateProperty<'a, Vec<Indexed<String>>>,
     Self: WrapTemplateProperty<'a, bool>,
     Self: WrapTemplateProperty<'a, i64>,
     Self: WrapTemplateProperty<'a, Option<i64>>,
@@ -159,6 +172,7 @@ where
     Self: WrapTemplateProperty<'a, Email>,
     Self: WrapTemplateProperty<'a, SizeHint>,
     Self: WrapTemplateProperty<'a, Timestamp>,
+    Self: WrapTemplateProperty<'a, Option<Timestamp>>,
     Self: WrapTemplateProperty<'a, TimestampRange>,
 {
     fn wrap_template(template: Box<dyn Template + 'a>) -> Self;
@@ -169,6 +183,8 @@ where
 
     fn try_into_boolean(self) -> Option<BoxedTemplateProperty<'a, bool>>;
     fn try_into_integer(self) -> Option<BoxedTemplateProperty<'a, i64>>;
+    fn try_into_timestamp(self) -> Option<BoxedTemplateProperty<'a, Timestamp>>;
+    fn try_into_string_list(self) -> Option<BoxedTemplateProperty<'a, Vec<String>>>;
 
     /// Transforms into a string property by formatting the value if needed.
     fn try_into_stringify(self) -> Option<BoxedTemplateProperty<'a, String>>;
@@ -184,7 +200,16 @@ where
 
 pub enum CoreTemplatePropertyKind<'a> {
     String(BoxedTemplateProperty<'a, String>),
+    StringOpt(BoxedTemplateProperty<'a, Option<String>>),
     StringList(BoxedTemplateProperty<'a, Vec<String>>),
+    StringPair(BoxedTemplateProperty<'a, Pair<String, String>>),
+    StringPairList(BoxedTemplateProperty<'a, Vec<Pair<String, String>>>),
+    StringListPair(BoxedTemplateProperty<'a, Pair<Vec<String>, Vec<String>>>),
+    StringGroup(BoxedTemplateProperty<'a, Pair<String, Vec<String>>>),
+    StringGroupList(BoxedTemplateProperty<'a, Vec<Pair<String, Vec<String>>>>),
+    StringIndexed(BoxedTemplateProperty<'a, Indexed<String>>),
+    StringIndexedList(BoxedTemplateProperty<'a, Vec<Indexed<String>>>),
+    Bytes(BoxedTemplateProperty<'a, BString>),
     Boolean(BoxedTemplateProperty<'a, bool>),
     Integer(BoxedTemplateProperty<'a, i64>),
     IntegerOpt(BoxedTemplateProperty<'a, Option<i64>>),
@@ -193,6 +218,7 @@ pub enum CoreTemplatePropertyKind<'a> {
     Email(BoxedTemplateProperty<'a, Email>),
     SizeHint(BoxedTemplateProperty<'a, SizeHint>),
     Timestamp(BoxedTemplateProperty<'a, Timestamp>),
+    TimestampOpt(BoxedTemplateProperty<'a, Option<Timestamp>>),
     TimestampRange(BoxedTemplateProperty<'a, TimestampRange>),
 
     // Both TemplateProperty and Template can represent a value to be evaluated
@@ -217,7 +243,16 @@ macro_rules! impl_core_property_wrappers {
     ($($head:tt)+) => {
         $crate::template_builder::impl_property_wrappers!($($head)+ {
             String(String),
+            StringOpt(Option<String>),
             StringList(Vec<String>),
+            StringPair(crate::templater::Pair<String, String>),
+            StringPairList(Vec<crate::templater::Pair<String, String>>),
+            StringListPair(crate::templater::Pair<Vec<String>, Vec<String>>),
+            StringGroup(crate::templater::Pair<String, Vec<String>>),
+            StringGroupList(Vec<crate::templater::Pair<String, Vec<String>>>),
+            StringIndexed(crate::templater::Indexed<String>),
+            StringIndexedList(Vec<crate::templater::Indexed<String>>),
+            Bytes(bstr::BString),
             Boolean(bool),
             Integer(i64),
             IntegerOpt(Option<i64>),
@@ -226,6 +261,7 @@ macro_rules! impl_core_property_wrappers {
             Email($crate::templater::Email),
             SizeHint($crate::templater::SizeHint),
             Timestamp(jj_lib::backend::Timestamp),
+            TimestampOpt(Option<jj_lib::backend::Timestamp>),
             TimestampRange(jj_lib::op_store::TimestampRange),
         });
     };
@@ -247,7 +283,16 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
     fn type_name(&self) -> &'static str {
         match self {
             Self::String(_) => "String",
+            Self::StringOpt(_) => "Option<String>",
             Self::StringList(_) => "List<String>",
+            Self::StringPair(_) => "Pair<String, String>",
+            Self::StringPairList(_) => "List<Pair<String, String>>",
+            Self::StringListPair(_) => "Pair<List<String>, List<String>>",
+            Self::StringGroup(_) => "Pair<String, List<String>>",
+            Self::StringGroupList(_) => "List<Pair<String, List<String>>>",
+            Self::StringIndexed(_) => "Indexed<String>",
+            Self::StringIndexedList(_) => "List<Indexed<String>>",
+            Self::Bytes(_) => "Bytes",
             Self::Boolean(_) => "Boolean",
             Self::Integer(_) => "Integer",
             Self::IntegerOpt(_) => "Option<Integer>",
@@ -256,6 +301,7 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
             Self::Email(_) => "Email",
             Self::SizeHint(_) => "SizeHint",
             Self::Timestamp(_) => "Timestamp",
+            Self::TimestampOpt(_) => "Option<Timestamp>",
             Self::TimestampRange(_) => "TimestampRange",
             Self::Template(_) => "Template",
             Self::ListTemplate(_) => "ListTemplate",
@@ -265,7 +311,16 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
     fn try_into_boolean(self) -> Option<BoxedTemplateProperty<'a, bool>> {
         match self {
             Self::String(property) => Some(property.map(|s| !s.is_empty()).into_dyn()),
+            Self::StringOpt(property) => Some(property.map(|opt| opt.is_some()).into_dyn()),
             Self::StringList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
+            Self::StringPair(_) => None,
+            Self::StringPairList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
+            Self::StringListPair(_) => None,
+            Self::StringGroup(_) => None,
+            Self::StringGroupList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
+            Self::StringIndexed(_) => None,
+            Self::StringIndexedList(property) => Some(property.map(|l| !l.is_empty()).into_dyn()),
+            Self::Bytes(property) => Some(property.map(|b| !b.is_empty()).into_dyn()),
             Self::Boolean(property) => Some(property),
             Self::Integer(_) => None,
             Self::IntegerOpt(property) => Some(property.map(|opt| opt.is_some()).into_dyn()),
@@ -274,6 +329,7 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
             Self::Email(property) => Some(property.map(|e| !e.0.is_empty()).into_dyn()),
             Self::SizeHint(_) => None,
             Self::Timestamp(_) => None,
+            Self::TimestampOpt(property) => Some(property.map(|opt| opt.is_some()).into_dyn()),
             Self::TimestampRange(_) => None,
             // Template types could also be evaluated to boolean, but it's less likely
             // to apply label() or .map() and use the result as conditional. It's also
@@ -291,6 +347,21 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
         }
     }
 
+    fn try_into_timestamp(self) -> Option<BoxedTemplateProperty<'a, Timestamp>> {
+        match self {
+            Self::Timestamp(property) => Some(property),
+            Self::TimestampOpt(property) => Some(property.try_unwrap("Timestamp").into_dyn()),
+            _ => None,
+        }
+    }
+
+    fn try_into_string_list(self) -> Option<BoxedTemplateProperty<'a, Vec<String>>> {
+        match self {
+            Self::StringList(property) => Some(property),
+            _ => None,
+        }
+    }
+
     fn try_into_stringify(self) -> Option<BoxedTemplateProperty<'a, String>> {
         match self {
             Self::String(property) => Some(property),
@@ -304,7 +375,16 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
     fn try_into_serialize(self) -> Option<BoxedSerializeProperty<'a>> {
         match self {
             Self::String(property) => Some(property.into_serialize()),
+            Self::StringOpt(property) => Some(property.into_serialize()),
             Self::StringList(property) => Some(property.into_serialize()),
+            Self::StringPair(_) => None,
+            Self::StringPairList(_) => None,
+            Self::StringListPair(_) => None,
+            Self::StringGroup(_) => None,
+            Self::StringGroupList(_) => None,
+            Self::StringIndexed(_) => None,
+            Self::StringIndexedList(_) => None,
+            Self::Bytes(_) => None,
             Self::Boolean(property) => Some(property.into_serialize()),
             Self::Integer(property) => Some(property.into_serialize()),
             Self::IntegerOpt(property) => Some(property.into_serialize()),
@@ -313,6 +393,7 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
             Self::Email(property) => Some(property.into_serialize()),
             Self::SizeHint(property) => Some(property.into_serialize()),
             Self::Timestamp(property) => Some(property.into_serialize()),
+            Self::TimestampOpt(property) => Some(property.into_serialize()),
             Self::TimestampRange(property) => Some(property.into_serialize()),
             Self::Template(_) => None,
             Self::ListTemplate(_) => None,
@@ -322,7 +403,16 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
     fn try_into_template(self) -> Option<Box<dyn Template + 'a>> {
         match self {
             Self::String(property) => Some(property.into_template()),
+            Self::StringOpt(property) => Some(property.into_template()),
             Self::StringList(property) => Some(property.into_template()),
+            Self::StringPair(_) => None,
+            Self::StringPairList(_) => None,
+            Self::StringListPair(_) => None,
+            Self::StringGroup(_) => None,
+            Self::StringGroupList(_) => None,
+            Self::StringIndexed(_) => None,
+            Self::StringIndexedList(_) => None,
+            Self::Bytes(property) => Some(property.into_template()),
             Self::Boolean(property) => Some(property.into_template()),
             Self::Integer(property) => Some(property.into_template()),
             Self::IntegerOpt(property) => Some(property.into_template()),
@@ -331,6 +421,7 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
             Self::Email(property) => Some(property.into_template()),
             Self::SizeHint(_) => None,
             Self::Timestamp(property) => Some(property.into_template()),
+            Self::TimestampOpt(property) => Some(property.into_template()),
             Self::TimestampRange(property) => Some(property.into_template()),
             Self::Template(template) => Some(template),
             Self::ListTemplate(template) => Some(template.into_template()),
@@ -342,9 +433,21 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
             (Self::String(lhs), Self::String(rhs)) => {
                 Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
             }
+            (Self::String(lhs), Self::StringOpt(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| Some(l) == r).into_dyn())
+            }
+            (Self::StringOpt(lhs), Self::String(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l == Some(r)).into_dyn())
+            }
+            (Self::StringOpt(lhs), Self::StringOpt(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
+            }
             (Self::String(lhs), Self::Email(rhs)) => {
                 Some((lhs, rhs).map(|(l, r)| l == r.0).into_dyn())
             }
+            (Self::Bytes(lhs), Self::Bytes(rhs)) => {
+                Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
+            }
             (Self::Boolean(lhs), Self::Boolean(rhs)) => {
                 Some((lhs, rhs).map(|(l, r)| l == r).into_dyn())
             }
@@ -367,7 +470,16 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
                 Some((lhs, rhs).map(|(l, r)| l.0 == r).into_dyn())
             }
             (Self::String(_), _) => None,
+            (Self::StringOpt(_), _) => None,
             (Self::StringList(_), _) => None,
+            (Self::StringPair(_), _) => None,
+            (Self::StringPairList(_), _) => None,
+            (Self::StringListPair(_), _) => None,
+            (Self::StringGroup(_), _) => None,
+            (Self::StringGroupList(_), _) => None,
+            (Self::StringIndexed(_), _) => None,
+            (Self::StringIndexedList(_), _) => None,
+            (Self::Bytes(_), _) => None,
             (Self::Boolean(_), _) => None,
             (Self::Integer(_), _) => None,
             (Self::IntegerOpt(_), _) => None,
@@ -376,6 +488,7 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
             (Self::Email(_), _) => None,
             (Self::SizeHint(_), _) => None,
             (Self::Timestamp(_), _) => None,
+            (Self::TimestampOpt(_), _) => None,
             (Self::TimestampRange(_), _) => None,
             (Self::Template(_), _) => None,
             (Self::ListTemplate(_), _) => None,
@@ -397,7 +510,16 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
                 Some((lhs, rhs).map(|(l, r)| l.cmp(&r)).into_dyn())
             }
             (Self::String(_), _) => None,
+            (Self::StringOpt(_), _) => None,
             (Self::StringList(_), _) => None,
+            (Self::StringPair(_), _) => None,
+            (Self::StringPairList(_), _) => None,
+            (Self::StringListPair(_), _) => None,
+            (Self::StringGroup(_), _) => None,
+            (Self::StringGroupList(_), _) => None,
+            (Self::StringIndexed(_), _) => None,
+            (Self::StringIndexedList(_), _) => None,
+            (Self::Bytes(_), _) => None,
             (Self::Boolean(_), _) => None,
             (Self::Integer(_), _) => None,
             (Self::IntegerOpt(_), _) => None,
@@ -406,6 +528,7 @@ impl<'a> CoreTemplatePropertyVar<'a> for CoreTemplatePropertyKind<'a> {
             (Self::Email(_), _) => None,
             (Self::SizeHint(_), _) => None,
             (Self::Timestamp(_), _) => None,
+            (Self::TimestampOpt(_), _) => None,
             (Self::TimestampRange(_), _) => None,
             (Self::Template(_), _) => None,
             (Self::ListTemplate(_), _) => None,
@@ -463,6 +586,14 @@ pub struct CoreTemplateBuildFnTable<'a, L: TemplateLanguage<'a> + ?Sized> {
     pub functions: TemplateBuildFunctionFnMap<'a, L>,
     pub string_methods: TemplateBuildMethodFnMap<'a, L, String>,
     pub string_list_methods: TemplateBuildMethodFnMap<'a, L, Vec<String>>,
+    pub string_pair_methods: TemplateBuildMethodFnMap<'a, L, Pair<String, String>>,
+    pub string_pair_list_methods: TemplateBuildMethodFnMap<'a, L, Vec<Pair<String, String>>>,
+    pub string_list_pair_methods: TemplateBuildMethodFnMap<'a, L, Pair<Vec<String>, Vec<String>>>,
+    pub string_group_methods: TemplateBuildMethodFnMap<'a, L, Pair<String, Vec<String>>>,
+    pub string_group_list_methods: TemplateBuildMethodFnMap<'a, L, Vec<Pair<String, Vec<String>>>>,
+    pub string_indexed_methods: TemplateBuildMethodFnMap<'a, L, Indexed<String>>,
+    pub string_indexed_list_methods: TemplateBuildMethodFnMap<'a, L, Vec<Indexed<String>>>,
+    pub bytes_methods: TemplateBuildMethodFnMap<'a, L, BString>,
     pub boolean_methods: TemplateBuildMethodFnMap<'a, L, bool>,
     pub integer_methods: TemplateBuildMethodFnMap<'a, L, i64>,
     pub config_value_methods: TemplateBuildMethodFnMap<'a, L, ConfigValue>,
@@ -489,7 +620,15 @@ impl<'a, L: TemplateLanguage<'a> + ?Sized> CoreTemplateBuildFnTable<'a, L> {
         Self {
             functions: builtin_functions(),
             string_methods: builtin_string_methods(),
-            string_list_methods: builtin_formattable_list_methods(),
+            string_list_methods: builtin_string_list_methods(),
+            string_pair_methods: builtin_pair_methods(),
+            string_pair_list_methods: builtin_unformattable_list_methods(),
+            string_list_pair_methods: builtin_partition_methods(),
+            string_group_methods: builtin_group_methods(),
+            string_group_list_methods: builtin_unformattable_list_methods(),
+            string_indexed_methods: builtin_indexed_methods(),
+            string_indexed_list_methods: builtin_unformattable_list_methods(),
+            bytes_methods: builtin_bytes_methods(),
             boolean_methods: HashMap::new(),
             integer_methods: HashMap::new(),
             config_value_methods: builtin_config_value_methods(),
@@ -508,6 +647,14 @@ impl<'a, L: TemplateLanguage<'a> + ?Sized> CoreTemplateBuildFnTable<'a, L> {
             functions: HashMap::new(),
             string_methods: HashMap::new(),
             string_list_methods: HashMap::new(),
+            string_pair_methods: HashMap::new(),
+            string_pair_list_methods: HashMap::new(),
+            string_list_pair_methods: HashMap::new(),
+            string_group_methods: HashMap::new(),
+            string_group_list_methods: HashMap::new(),
+            string_indexed_methods: HashMap::new(),
+            string_indexed_list_methods: HashMap::new(),
+            bytes_methods: HashMap::new(),
             boolean_methods: HashMap::new(),
             integer_methods: HashMap::new(),
             config_value_methods: HashMap::new(),
@@ -526,6 +673,14 @@ impl<'a, L: TemplateLanguage<'a> + ?Sized> CoreTemplateBuildFnTable<'a, L> {
             functions,
             string_methods,
             string_list_methods,
+            string_pair_methods,
+            string_pair_list_methods,
+            string_list_pair_methods,
+            string_group_methods,
+            string_group_list_methods,
+            string_indexed_methods,
+            string_indexed_list_methods,
+            bytes_methods,
             boolean_methods,
             integer_methods,
             config_value_methods,
@@ -541,6 +696,20 @@ impl<'a, L: TemplateLanguage<'a> + ?Sized> CoreTemplateBuildFnTable<'a, L> {
         merge_fn_map(&mut self.functions, functions);
         merge_fn_map(&mut self.string_methods, string_methods);
         merge_fn_map(&mut self.string_list_methods, string_list_methods);
+        merge_fn_map(&mut self.string_pair_methods, string_pair_methods);
+        merge_fn_map(&mut self.string_pair_list_methods, string_pair_list_methods);
+        merge_fn_map(&mut self.string_list_pair_methods, string_list_pair_methods);
+        merge_fn_map(&mut self.string_group_methods, string_group_methods);
+        merge_fn_map(
+            &mut self.string_group_list_methods,
+            string_group_list_methods,
+        );
+        merge_fn_map(&mut self.string_indexed_methods, string_indexed_methods);
+        merge_fn_map(
+            &mut self.string_indexed_list_methods,
+            string_indexed_list_methods,
+        );
+        merge_fn_map(&mut self.bytes_methods, bytes_methods);
         merge_fn_map(&mut self.boolean_methods, boolean_methods);
         merge_fn_map(&mut self.integer_methods, integer_methods);
         merge_fn_map(&mut self.config_value_methods, config_value_methods);
@@ -583,11 +752,58 @@ impl<'a, L: TemplateLanguage<'a> + ?Sized> CoreTemplateBuildFnTable<'a, L> {
                 let build = template_parser::lookup_method(type_name, table, function)?;
                 build(language, diagnostics, build_ctx, property, function)
             }
+            CoreTemplatePropertyKind::StringOpt(property) => {
+                let type_name = "String";
+                let table = &self.string_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                let inner_property = property.try_unwrap(type_name).into_dyn();
+                build(language, diagnostics, build_ctx, inner_property, function)
+            }
             CoreTemplatePropertyKind::StringList(property) => {
                 let table = &self.string_list_methods;
                 let build = template_parser::lookup_method(type_name, table, function)?;
                 build(language, diagnostics, build_ctx, property, function)
             }
+            CoreTemplatePropertyKind::StringPair(property) => {
+                let table = &self.string_pair_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
+            CoreTemplatePropertyKind::StringPairList(property) => {
+                let table = &self.string_pair_list_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
+            CoreTemplatePropertyKind::StringListPair(property) => {
+                let table = &self.string_list_pair_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
+            CoreTemplatePropertyKind::StringGroup(property) => {
+                let table = &self.string_group_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
+            CoreTemplatePropertyKind::StringGroupList(property) => {
+                let table = &self.string_group_list_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
+            CoreTemplatePropertyKind::StringIndexed(property) => {
+                let table = &self.string_indexed_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
+            CoreTemplatePropertyKind::StringIndexedList(property) => {
+                let table = &self.string_indexed_list_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
+            CoreTemplatePropertyKind::Bytes(property) => {
+                let table = &self.bytes_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                build(language, diagnostics, build_ctx, property, function)
+            }
             CoreTemplatePropertyKind::Boolean(property) => {
                 let table = &self.boolean_methods;
                 let build = template_parser::lookup_method(type_name, table, function)?;
@@ -630,6 +846,13 @@ impl<'a, L: TemplateLanguage<'a> + ?Sized> CoreTemplateBuildFnTable<'a, L> {
                 let build = template_parser::lookup_method(type_name, table, function)?;
                 build(language, diagnostics, build_ctx, property, function)
             }
+            CoreTemplatePropertyKind::TimestampOpt(property) => {
+                let type_name = "Timestamp";
+                let table = &self.timestamp_methods;
+                let build = template_parser::lookup_method(type_name, table, function)?;
+                let inner_property = property.try_unwrap(type_name).into_dyn();
+                build(language, diagnostics, build_ctx, inner_property, function)
+            }
             CoreTemplatePropertyKind::TimestampRange(property) => {
                 let table = &self.timestamp_range_methods;
                 let build = template_parser::lookup_method(type_name, table, function)?;
@@ -680,6 +903,14 @@ impl<'a, P: CoreTemplatePropertyVar<'a>> Expression<P> {
         self.property.try_into_integer()
     }
 
+    pub fn try_into_timestamp(self) -> Option<BoxedTemplateProperty<'a, Timestamp>> {
+        self.property.try_into_timestamp()
+    }
+
+    pub fn try_into_string_list(self) -> Option<BoxedTemplateProperty<'a, Vec<String>>> {
+        self.property.try_into_string_list()
+    }
+
     pub fn try_into_stringify(self) -> Option<BoxedTemplateProperty<'a, String>> {
         self.property.try_into_stringify()
     }
@@ -998,6 +1229,20 @@ fn builtin_string_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "summary_line",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [width_node] = function.expect_exact_arguments()?;
+            let width_property =
+                expect_usize_expression(language, diagnostics, build_ctx, width_node)?;
+            let out_property = (self_property, width_property).map(|(s, width)| {
+                let first_line = s.lines().next().unwrap_or_default();
+                let (summary, _) = text_util::elide_end(first_line, "…", width);
+                summary.into_owned()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "lines",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -1006,6 +1251,35 @@ fn builtin_string_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "wrap",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [width_node] = function.expect_exact_arguments()?;
+            let width_property =
+                expect_usize_expression(language, diagnostics, build_ctx, width_node)?;
+            let out_property = (self_property, width_property)
+                .map(|(s, width)| text_util::reflow_paragraphs(&s, width));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "autolink",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [pattern_node, url_node] = function.expect_exact_arguments()?;
+            let regex = template_parser::catch_aliases(diagnostics, pattern_node, |_, node| {
+                let pattern = template_parser::expect_string_literal(node)?;
+                regex::Regex::new(pattern).map_err(|err| {
+                    TemplateParseError::expression("Invalid regular expression", node.span)
+                        .with_source(err)
+                })
+            })?;
+            let url_property =
+                expect_stringify_expression(language, diagnostics, build_ctx, url_node)?;
+            let out_property = (self_property, url_property)
+                .map(move |(s, url)| regex.replace_all(&s, url.as_str()).into_owned());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map.insert(
         "upper",
         |_language, _diagnostics, _build_ctx, self_property, function| {
@@ -1030,6 +1304,14 @@ fn builtin_string_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "escape_template",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|s| template_parser::escape_string_literal(&s));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
@@ -1146,6 +1428,31 @@ fn builtin_signature_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "name_key",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property
+                .map(|signature| signature.name.split_whitespace().join(" ").to_lowercase());
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map
+}
+
+fn builtin_bytes_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
+-> TemplateBuildMethodFnMap<'a, L, BString> {
+    // Not using maplit::hashmap!{} or custom declarative macro here because
+    // code completion inside macro is quite restricted.
+    let mut map = TemplateBuildMethodFnMap::<L, BString>::new();
+    map.insert(
+        "len",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|bytes| Ok(i64::try_from(bytes.len())?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
@@ -1308,6 +1615,141 @@ fn builtin_timestamp_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
         },
     );
     map.insert("before", map["after"]);
+    map.insert(
+        "relative_bucket",
+        |_language, diagnostics, _build_ctx, self_property, function| {
+            let ([], [today_node, yesterday_node, this_week_node, older_node]) =
+                function.expect_named_arguments(&["today", "yesterday", "this_week", "older"])?;
+            let mut expect_label = |node: Option<&ExpressionNode>, default: &str| match node {
+                Some(node) => {
+                    template_parser::catch_aliases(diagnostics, node, |_diagnostics, node| {
+                        Ok(template_parser::expect_string_literal(node)?.to_owned())
+                    })
+                }
+                None => Ok(default.to_owned()),
+            };
+            let today = expect_label(today_node, "today")?;
+            let yesterday = expect_label(yesterday_node, "yesterday")?;
+            let this_week = expect_label(this_week_node, "this week")?;
+            let older = expect_label(older_node, "older")?;
+            let now = Timestamp::now();
+            let out_property = self_property.and_then(move |timestamp| {
+                let label = match time_util::relative_bucket(&timestamp, &now)? {
+                    time_util::RelativeBucket::Today => &today,
+                    time_util::RelativeBucket::Yesterday => &yesterday,
+                    time_util::RelativeBucket::ThisWeek => &this_week,
+                    time_util::RelativeBucket::Older => &older,
+                };
+                Ok(label.clone())
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "is_future",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            // Compared against the wall clock at the time the template is built.
+            let now = Timestamp::now();
+            let out_property =
+                self_property.map(move |timestamp| timestamp.timestamp > now.timestamp);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "quarter",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|timestamp| Ok(i64::from(time_util::quarter(&timestamp)?)));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "iso_week",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|timestamp| Ok(i64::from(time_util::iso_week(&timestamp)?)));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "weekday_name",
+        |_language, diagnostics, _build_ctx, self_property, function| {
+            let ([], [names_node]) = function.expect_named_arguments(&["names"])?;
+            let names: Vec<String> = if let Some(node) = names_node {
+                template_parser::catch_aliases(diagnostics, node, |_diagnostics, node| {
+                    let names = template_parser::expect_string_literal(node)?;
+                    let names = names.split_whitespace().map(str::to_owned).collect_vec();
+                    if names.len() != 7 {
+                        let message = "Expected 7 weekday names starting from Monday";
+                        return Err(TemplateParseError::expression(message, node.span));
+                    }
+                    Ok(names)
+                })?
+            } else {
+                [
+                    "Monday",
+                    "Tuesday",
+                    "Wednesday",
+                    "Thursday",
+                    "Friday",
+                    "Saturday",
+                    "Sunday",
+                ]
+                .map(str::to_owned)
+                .into()
+            };
+            let out_property = self_property.and_then(move |timestamp| {
+                let index = time_util::weekday(&timestamp)?;
+                Ok(names[index as usize].clone())
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "business_days_since",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [other_node] = function.expect_exact_arguments()?;
+            let other_property =
+                expect_timestamp_expression(language, diagnostics, build_ctx, other_node)?;
+            let out_property = (self_property, other_property).and_then(|(timestamp, other)| {
+                Ok(time_util::business_days_between(&other, &timestamp)?)
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "unix_millis",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|timestamp| timestamp.timestamp.0);
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "min",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [other_node] = function.expect_exact_arguments()?;
+            let other_property =
+                expect_timestamp_expression(language, diagnostics, build_ctx, other_node)?;
+            let out_property = (self_property, other_property)
+                .map(|(timestamp, other)| cmp::min_by_key(timestamp, other, |t| t.timestamp));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "max",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [other_node] = function.expect_exact_arguments()?;
+            let other_property =
+                expect_timestamp_expression(language, diagnostics, build_ctx, other_node)?;
+            let out_property = (self_property, other_property)
+                .map(|(timestamp, other)| cmp::max_by_key(timestamp, other, |t| t.timestamp));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
@@ -1370,6 +1812,44 @@ fn builtin_list_template_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
     map
 }
 
+fn builtin_string_list_methods<'a, L: TemplateLanguage<'a> + ?Sized>()
+-> TemplateBuildMethodFnMap<'a, L, Vec<String>> {
+    let mut map = builtin_formattable_list_methods::<L, String>();
+    map.insert(
+        "intersperse",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [separator_node] = function.expect_exact_arguments()?;
+            let separator_property =
+                expect_stringify_expression(language, diagnostics, build_ctx, separator_node)?;
+            let out_property = (self_property, separator_property).map(|(items, separator)| {
+                itertools::intersperse(items, separator).collect::<Vec<_>>()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert("enumerate", build_enumerate_method);
+    map.insert("find", build_find_method);
+    map.insert("group_by", build_group_by_method);
+    map.insert("max_by", build_max_by_method);
+    map.insert("min_by", build_min_by_method);
+    map.insert("partition", build_partition_method);
+    map.insert(
+        "zip",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let [other_node] = function.expect_exact_arguments()?;
+            let other_property =
+                expect_string_list_expression(language, diagnostics, build_ctx, other_node)?;
+            let out_property = (self_property, other_property).map(|(items, others)| {
+                iter::zip(items, others)
+                    .map(|(first, second)| Pair { first, second })
+                    .collect::<Vec<_>>()
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map
+}
+
 /// Creates new symbol table for printable list property.
 pub fn builtin_formattable_list_methods<'a, L, O>() -> TemplateBuildMethodFnMap<'a, L, Vec<O>>
 where
@@ -1420,6 +1900,42 @@ where
             Ok(L::Property::wrap_property(out_property))
         },
     );
+    map.insert(
+        "any",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let out_property = build_any_all_operation(
+                language,
+                diagnostics,
+                build_ctx,
+                self_property,
+                function,
+                false,
+            )?;
+            Ok(L::Property::wrap_property(out_property))
+        },
+    );
+    map.insert(
+        "all",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let out_property = build_any_all_operation(
+                language,
+                diagnostics,
+                build_ctx,
+                self_property,
+                function,
+                true,
+            )?;
+            Ok(L::Property::wrap_property(out_property))
+        },
+    );
+    map.insert(
+        "count",
+        |language, diagnostics, build_ctx, self_property, function| {
+            let out_property =
+                build_count_operation(language, diagnostics, build_ctx, self_property, function)?;
+            Ok(L::Property::wrap_property(out_property))
+        },
+    );
     map.insert(
         "map",
         |language, diagnostics, build_ctx, self_property, function| {
@@ -1431,21 +1947,22 @@ where
     map
 }
 
-/// Builds expression that extracts iterable property and filters its items.
-fn build_filter_operation<'a, L, O, P, B>(
+/// Builds `list.find(|item| predicate)` expression which selects the first
+/// item satisfying the predicate.
+///
+/// This can be inserted to the method table of list types for which
+/// `Option<O>` type is defined.
+pub fn build_find_method<'a, L, O>(
     language: &L,
     diagnostics: &mut TemplateDiagnostics,
     build_ctx: &BuildContext<L::Property>,
-    self_property: P,
+    self_property: BoxedTemplateProperty<'a, Vec<O>>,
     function: &FunctionCallNode,
-) -> TemplateParseResult<BoxedTemplateProperty<'a, B>>
+) -> TemplateParseResult<L::Property>
 where
     L: TemplateLanguage<'a> + ?Sized,
-    L::Property: WrapTemplateProperty<'a, O>,
-    P: TemplateProperty + 'a,
-    P::Output: IntoIterator<Item = O>,
+    L::Property: WrapTemplateProperty<'a, O> + WrapTemplateProperty<'a, Option<O>>,
     O: Clone + 'a,
-    B: FromIterator<O>,
 {
     let [lambda_node] = function.expect_exact_arguments()?;
     let item_placeholder = PropertyPlaceholder::new();
@@ -1460,61 +1977,587 @@ where
             )
         })?;
     let out_property = self_property.and_then(move |items| {
-        items
-            .into_iter()
-            .filter_map(|item| {
-                // Evaluate predicate with the current item
-                item_placeholder.set(item);
-                let result = item_predicate.extract();
-                let item = item_placeholder.take().unwrap();
-                result.map(|pred| pred.then_some(item)).transpose()
-            })
-            .collect()
+        for item in items {
+            // Evaluate predicate with the current item
+            item_placeholder.set(item);
+            let result = item_predicate.extract();
+            let item = item_placeholder.take().unwrap();
+            if result? {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
     });
-    Ok(out_property.into_dyn())
+    Ok(L::Property::wrap_property(out_property.into_dyn()))
 }
 
-/// Builds expression that extracts iterable property and applies template to
-/// each item.
-fn build_map_operation<'a, L, O, P>(
+/// Builds `list.partition(|item| predicate)` expression which splits the list
+/// into the items satisfying the predicate and the others in one pass.
+///
+/// This can be inserted to the method table of list types for which
+/// `Pair<Vec<O>, Vec<O>>` type is defined.
+pub fn build_partition_method<'a, L, O>(
     language: &L,
     diagnostics: &mut TemplateDiagnostics,
     build_ctx: &BuildContext<L::Property>,
-    self_property: P,
+    self_property: BoxedTemplateProperty<'a, Vec<O>>,
     function: &FunctionCallNode,
-) -> TemplateParseResult<Box<dyn ListTemplate + 'a>>
+) -> TemplateParseResult<L::Property>
 where
     L: TemplateLanguage<'a> + ?Sized,
-    L::Property: WrapTemplateProperty<'a, O>,
-    P: TemplateProperty + 'a,
-    P::Output: IntoIterator<Item = O>,
+    L::Property: WrapTemplateProperty<'a, O> + WrapTemplateProperty<'a, Pair<Vec<O>, Vec<O>>>,
     O: Clone + 'a,
 {
     let [lambda_node] = function.expect_exact_arguments()?;
     let item_placeholder = PropertyPlaceholder::new();
-    let item_template =
+    let item_predicate =
         template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
             let lambda = template_parser::expect_lambda(node)?;
             build_lambda_expression(
                 build_ctx,
                 lambda,
                 &[&|| item_placeholder.clone().into_dyn_wrapped()],
-                |build_ctx, body| {
-                    expect_template_expression(language, diagnostics, build_ctx, body)
-                },
+                |build_ctx, body| expect_boolean_expression(language, diagnostics, build_ctx, body),
             )
         })?;
-    let list_template = ListPropertyTemplate::new(
-        self_property,
-        Literal(" "), // separator
-        move |formatter, item| {
-            item_placeholder.with_value(item, || item_template.format(formatter))
-        },
-    );
-    Ok(Box::new(list_template))
-}
-
-/// Builds lambda expression to be evaluated with the provided arguments.
+    let out_property = self_property.and_then(move |items| {
+        let mut matched = Vec::new();
+        let mut unmatched = Vec::new();
+        for item in items {
+            // Evaluate predicate with the current item
+            item_placeholder.set(item);
+            let result = item_predicate.extract();
+            let item = item_placeholder.take().unwrap();
+            if result? {
+                matched.push(item);
+            } else {
+                unmatched.push(item);
+            }
+        }
+        Ok(Pair {
+            first: matched,
+            second: unmatched,
+        })
+    });
+    Ok(L::Property::wrap_property(out_property.into_dyn()))
+}
+
+/// Builds `list.group_by(|item| key)` expression which groups the items by
+/// stringified key. Groups are ordered by first occurrence of the key.
+///
+/// This can be inserted to the method table of list types for which
+/// `Vec<Pair<String, Vec<O>>>` type is defined.
+pub fn build_group_by_method<'a, L, O>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: BoxedTemplateProperty<'a, Vec<O>>,
+    function: &FunctionCallNode,
+) -> TemplateParseResult<L::Property>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O> + WrapTemplateProperty<'a, Vec<Pair<String, Vec<O>>>>,
+    O: Clone + 'a,
+{
+    let [lambda_node] = function.expect_exact_arguments()?;
+    let item_placeholder = PropertyPlaceholder::new();
+    let item_key =
+        template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
+            let lambda = template_parser::expect_lambda(node)?;
+            build_lambda_expression(
+                build_ctx,
+                lambda,
+                &[&|| item_placeholder.clone().into_dyn_wrapped()],
+                |build_ctx, body| {
+                    expect_stringify_expression(language, diagnostics, build_ctx, body)
+                },
+            )
+        })?;
+    let out_property = self_property.and_then(move |items| {
+        let mut groups: Vec<Pair<String, Vec<O>>> = Vec::new();
+        let mut group_indices: HashMap<String, usize> = HashMap::new();
+        for item in items {
+            // Evaluate key with the current item
+            item_placeholder.set(item);
+            let result = item_key.extract();
+            let item = item_placeholder.take().unwrap();
+            let key = result?;
+            if let Some(&index) = group_indices.get(&key) {
+                groups[index].second.push(item);
+            } else {
+                group_indices.insert(key.clone(), groups.len());
+                groups.push(Pair {
+                    first: key,
+                    second: vec![item],
+                });
+            }
+        }
+        Ok(groups)
+    });
+    Ok(L::Property::wrap_property(out_property.into_dyn()))
+}
+
+/// Builds `list.max_by(|item| key)` expression which selects the item of the
+/// largest key.
+///
+/// This can be inserted to the method table of list types for which
+/// `Option<O>` type is defined.
+pub fn build_max_by_method<'a, L, O>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: BoxedTemplateProperty<'a, Vec<O>>,
+    function: &FunctionCallNode,
+) -> TemplateParseResult<L::Property>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O> + WrapTemplateProperty<'a, Option<O>>,
+    O: Clone + 'a,
+{
+    let out_property = build_extremal_by_operation(
+        language,
+        diagnostics,
+        build_ctx,
+        self_property,
+        function,
+        Ordering::Greater,
+    )?;
+    Ok(L::Property::wrap_property(out_property))
+}
+
+/// Builds `list.min_by(|item| key)` expression which selects the item of the
+/// smallest key.
+///
+/// This can be inserted to the method table of list types for which
+/// `Option<O>` type is defined.
+pub fn build_min_by_method<'a, L, O>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: BoxedTemplateProperty<'a, Vec<O>>,
+    function: &FunctionCallNode,
+) -> TemplateParseResult<L::Property>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O> + WrapTemplateProperty<'a, Option<O>>,
+    O: Clone + 'a,
+{
+    let out_property = build_extremal_by_operation(
+        language,
+        diagnostics,
+        build_ctx,
+        self_property,
+        function,
+        Ordering::Less,
+    )?;
+    Ok(L::Property::wrap_property(out_property))
+}
+
+/// Builds list template which formats items matching the `predicate` by the
+/// lambda template, and the other items as they are.
+///
+/// This can be used to implement `list.format_with(pattern, |item| ..)`-like
+/// methods for list types of printable items.
+pub fn build_partial_map_template<'a, L, O, P, S>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: P,
+    separator: S,
+    lambda_node: &ExpressionNode,
+    predicate: impl Fn(&O) -> bool + 'a,
+) -> TemplateParseResult<Box<dyn ListTemplate + 'a>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O>,
+    P: TemplateProperty + 'a,
+    P::Output: IntoIterator<Item = O>,
+    S: Template + 'a,
+    O: Template + Clone + 'a,
+{
+    let item_placeholder = PropertyPlaceholder::new();
+    let item_template =
+        template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
+            let lambda = template_parser::expect_lambda(node)?;
+            build_lambda_expression(
+                build_ctx,
+                lambda,
+                &[&|| item_placeholder.clone().into_dyn_wrapped()],
+                |build_ctx, body| {
+                    expect_template_expression(language, diagnostics, build_ctx, body)
+                },
+            )
+        })?;
+    let list_template =
+        ListPropertyTemplate::new(self_property, separator, move |formatter, item| {
+            if predicate(&item) {
+                item_placeholder.with_value(item, || item_template.format(formatter))
+            } else {
+                item.format(formatter)
+            }
+        });
+    Ok(Box::new(list_template))
+}
+
+/// Creates new symbol table for list item paired with its index.
+pub fn builtin_indexed_methods<'a, L, O>() -> TemplateBuildMethodFnMap<'a, L, Indexed<O>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O>,
+    O: 'a,
+{
+    // Not using maplit::hashmap!{} or custom declarative macro here because
+    // code completion inside macro is quite restricted.
+    let mut map = TemplateBuildMethodFnMap::<L, Indexed<O>>::new();
+    map.insert(
+        "index",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|item| Ok(i64::try_from(item.index)?));
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "value",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|item| item.value);
+            Ok(L::Property::wrap_property(out_property.into_dyn()))
+        },
+    );
+    map
+}
+
+/// Creates new symbol table for pair of list elements.
+pub fn builtin_pair_methods<'a, L, T, U>() -> TemplateBuildMethodFnMap<'a, L, Pair<T, U>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, T> + WrapTemplateProperty<'a, U>,
+    T: 'a,
+    U: 'a,
+{
+    // Not using maplit::hashmap!{} or custom declarative macro here because
+    // code completion inside macro is quite restricted.
+    let mut map = TemplateBuildMethodFnMap::<L, Pair<T, U>>::new();
+    map.insert(
+        "first",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|pair| pair.first);
+            Ok(L::Property::wrap_property(out_property.into_dyn()))
+        },
+    );
+    map.insert(
+        "second",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|pair| pair.second);
+            Ok(L::Property::wrap_property(out_property.into_dyn()))
+        },
+    );
+    map
+}
+
+/// Creates new symbol table for pair of lists split by `List.partition()`.
+pub fn builtin_partition_methods<'a, L: TemplateLanguage<'a> + ?Sized, O: 'a>()
+-> TemplateBuildMethodFnMap<'a, L, Pair<Vec<O>, Vec<O>>>
+where
+    L::Property: WrapTemplateProperty<'a, Vec<O>>,
+{
+    let mut map = builtin_pair_methods::<L, Vec<O>, Vec<O>>();
+    map.insert(
+        "matched",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|pair| pair.first);
+            Ok(L::Property::wrap_property(out_property.into_dyn()))
+        },
+    );
+    map.insert(
+        "unmatched",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|pair| pair.second);
+            Ok(L::Property::wrap_property(out_property.into_dyn()))
+        },
+    );
+    map
+}
+
+/// Creates new symbol table for pair of key and list elements grouped by
+/// `List.group_by()`.
+pub fn builtin_group_methods<'a, L: TemplateLanguage<'a> + ?Sized, O: 'a>()
+-> TemplateBuildMethodFnMap<'a, L, Pair<String, Vec<O>>>
+where
+    L::Property: WrapTemplateProperty<'a, Vec<O>>,
+{
+    let mut map = builtin_pair_methods::<L, String, Vec<O>>();
+    map.insert(
+        "key",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|pair| pair.first);
+            Ok(L::Property::wrap_property(out_property.into_dyn()))
+        },
+    );
+    map.insert(
+        "items",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.map(|pair| pair.second);
+            Ok(L::Property::wrap_property(out_property.into_dyn()))
+        },
+    );
+    map
+}
+
+/// Builds `list.enumerate()` expression which pairs each item with its index.
+///
+/// This can be inserted to the method table of list types for which
+/// `Indexed<O>` element and list types are defined.
+pub fn build_enumerate_method<'a, L, O>(
+    _language: &L,
+    _diagnostics: &mut TemplateDiagnostics,
+    _build_ctx: &BuildContext<L::Property>,
+    self_property: BoxedTemplateProperty<'a, Vec<O>>,
+    function: &FunctionCallNode,
+) -> TemplateParseResult<L::Property>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, Vec<Indexed<O>>>,
+    O: 'a,
+{
+    function.expect_no_arguments()?;
+    let out_property = self_property.map(|items| {
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| Indexed { index, value })
+            .collect::<Vec<_>>()
+    });
+    Ok(L::Property::wrap_property(out_property.into_dyn()))
+}
+
+/// Builds expression that extracts iterable property and filters its items.
+fn build_filter_operation<'a, L, O, P, B>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: P,
+    function: &FunctionCallNode,
+) -> TemplateParseResult<BoxedTemplateProperty<'a, B>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O>,
+    P: TemplateProperty + 'a,
+    P::Output: IntoIterator<Item = O>,
+    O: Clone + 'a,
+    B: FromIterator<O>,
+{
+    let [lambda_node] = function.expect_exact_arguments()?;
+    let item_placeholder = PropertyPlaceholder::new();
+    let item_predicate =
+        template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
+            let lambda = template_parser::expect_lambda(node)?;
+            build_lambda_expression(
+                build_ctx,
+                lambda,
+                &[&|| item_placeholder.clone().into_dyn_wrapped()],
+                |build_ctx, body| expect_boolean_expression(language, diagnostics, build_ctx, body),
+            )
+        })?;
+    let out_property = self_property.and_then(move |items| {
+        items
+            .into_iter()
+            .filter_map(|item| {
+                // Evaluate predicate with the current item
+                item_placeholder.set(item);
+                let result = item_predicate.extract();
+                let item = item_placeholder.take().unwrap();
+                result.map(|pred| pred.then_some(item)).transpose()
+            })
+            .collect()
+    });
+    Ok(out_property.into_dyn())
+}
+
+/// Builds expression that extracts iterable property and tests whether any
+/// (or all if `all` is true) of its items satisfy the predicate.
+///
+/// Evaluation stops at the first item that determines the result.
+fn build_any_all_operation<'a, L, O, P>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: P,
+    function: &FunctionCallNode,
+    all: bool,
+) -> TemplateParseResult<BoxedTemplateProperty<'a, bool>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O>,
+    P: TemplateProperty + 'a,
+    P::Output: IntoIterator<Item = O>,
+    O: Clone + 'a,
+{
+    let [lambda_node] = function.expect_exact_arguments()?;
+    let item_placeholder = PropertyPlaceholder::new();
+    let item_predicate =
+        template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
+            let lambda = template_parser::expect_lambda(node)?;
+            build_lambda_expression(
+                build_ctx,
+                lambda,
+                &[&|| item_placeholder.clone().into_dyn_wrapped()],
+                |build_ctx, body| expect_boolean_expression(language, diagnostics, build_ctx, body),
+            )
+        })?;
+    let out_property = self_property.and_then(move |items| {
+        for item in items {
+            let pred = item_placeholder.with_value(item, || item_predicate.extract())?;
+            if pred != all {
+                return Ok(pred);
+            }
+        }
+        Ok(all)
+    });
+    Ok(out_property.into_dyn())
+}
+
+/// Builds expression that extracts iterable property and counts items
+/// satisfying the predicate.
+fn build_count_operation<'a, L, O, P>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: P,
+    function: &FunctionCallNode,
+) -> TemplateParseResult<BoxedTemplateProperty<'a, i64>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O>,
+    P: TemplateProperty + 'a,
+    P::Output: IntoIterator<Item = O>,
+    O: Clone + 'a,
+{
+    let [lambda_node] = function.expect_exact_arguments()?;
+    let item_placeholder = PropertyPlaceholder::new();
+    let item_predicate =
+        template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
+            let lambda = template_parser::expect_lambda(node)?;
+            build_lambda_expression(
+                build_ctx,
+                lambda,
+                &[&|| item_placeholder.clone().into_dyn_wrapped()],
+                |build_ctx, body| expect_boolean_expression(language, diagnostics, build_ctx, body),
+            )
+        })?;
+    let out_property = self_property.and_then(move |items| {
+        let mut count: usize = 0;
+        for item in items {
+            if item_placeholder.with_value(item, || item_predicate.extract())? {
+                count += 1;
+            }
+        }
+        Ok(i64::try_from(count)?)
+    });
+    Ok(out_property.into_dyn())
+}
+
+/// Builds expression that extracts iterable property and selects the first
+/// item whose key is the most `preferred` compared to the other items.
+///
+/// The key should be of `Integer` or `Timestamp` type.
+fn build_extremal_by_operation<'a, L, O, P>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: P,
+    function: &FunctionCallNode,
+    preferred: Ordering,
+) -> TemplateParseResult<BoxedTemplateProperty<'a, Option<O>>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O>,
+    P: TemplateProperty + 'a,
+    P::Output: IntoIterator<Item = O>,
+    O: Clone + 'a,
+{
+    let [lambda_node] = function.expect_exact_arguments()?;
+    let item_placeholder = PropertyPlaceholder::new();
+    let item_key =
+        template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
+            let lambda = template_parser::expect_lambda(node)?;
+            build_lambda_expression(
+                build_ctx,
+                lambda,
+                &[&|| item_placeholder.clone().into_dyn_wrapped()],
+                |build_ctx, body| {
+                    expect_sort_key_expression(language, diagnostics, build_ctx, body)
+                },
+            )
+        })?;
+    let out_property = self_property.and_then(move |items| {
+        let mut selected: Option<(i64, O)> = None;
+        for item in items {
+            // Evaluate key with the current item
+            item_placeholder.set(item);
+            let result = item_key.extract();
+            let item = item_placeholder.take().unwrap();
+            let key = result?;
+            if selected
+                .as_ref()
+                .is_none_or(|(selected_key, _)| key.cmp(selected_key) == preferred)
+            {
+                selected = Some((key, item));
+            }
+        }
+        Ok(selected.map(|(_, item)| item))
+    });
+    Ok(out_property.into_dyn())
+}
+
+/// Builds expression that extracts iterable property and applies template to
+/// each item.
+fn build_map_operation<'a, L, O, P>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    self_property: P,
+    function: &FunctionCallNode,
+) -> TemplateParseResult<Box<dyn ListTemplate + 'a>>
+where
+    L: TemplateLanguage<'a> + ?Sized,
+    L::Property: WrapTemplateProperty<'a, O>,
+    P: TemplateProperty + 'a,
+    P::Output: IntoIterator<Item = O>,
+    O: Clone + 'a,
+{
+    let [lambda_node] = function.expect_exact_arguments()?;
+    let item_placeholder = PropertyPlaceholder::new();
+    let item_template =
+        template_parser::catch_aliases(diagnostics, lambda_node, |diagnostics, node| {
+            let lambda = template_parser::expect_lambda(node)?;
+            build_lambda_expression(
+                build_ctx,
+                lambda,
+                &[&|| item_placeholder.clone().into_dyn_wrapped()],
+                |build_ctx, body| {
+                    expect_template_expression(language, diagnostics, build_ctx, body)
+                },
+            )
+        })?;
+    let list_template = ListPropertyTemplate::new(
+        self_property,
+        Literal(" "), // separator
+        move |formatter, item| {
+            item_placeholder.with_value(item, || item_template.format(formatter))
+        },
+    );
+    Ok(Box::new(list_template))
+}
+
+/// Builds lambda expression to be evaluated with the provided arguments.
 /// `arg_fns` is usually an array of wrapped [`PropertyPlaceholder`]s.
 fn build_lambda_expression<'i, P, T>(
     build_ctx: &BuildContext<'i, P>,
@@ -1656,6 +2699,13 @@ fn builtin_functions<'a, L: TemplateLanguage<'a> + ?Sized>() -> TemplateBuildFun
             )))
         },
     );
+    map.insert("hyperlink", |language, diagnostics, build_ctx, function| {
+        let [url_node, content_node] = function.expect_exact_arguments()?;
+        let url = expect_stringify_expression(language, diagnostics, build_ctx, url_node)?;
+        let content = expect_template_expression(language, diagnostics, build_ctx, content_node)?;
+        let template = HyperlinkTemplate { url, content };
+        Ok(L::Property::wrap_template(Box::new(template)))
+    });
     map.insert("stringify", |language, diagnostics, build_ctx, function| {
         let [content_node] = function.expect_exact_arguments()?;
         let content = expect_stringify_expression(language, diagnostics, build_ctx, content_node)?;
@@ -1990,6 +3040,62 @@ pub fn expect_usize_expression<'a, L: TemplateLanguage<'a> + ?Sized>(
     Ok(usize_property.into_dyn())
 }
 
+pub fn expect_timestamp_expression<'a, L: TemplateLanguage<'a> + ?Sized>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    node: &ExpressionNode,
+) -> TemplateParseResult<BoxedTemplateProperty<'a, Timestamp>> {
+    expect_expression_of_type(
+        language,
+        diagnostics,
+        build_ctx,
+        node,
+        "Timestamp",
+        |expression| expression.try_into_timestamp(),
+    )
+}
+
+pub fn expect_string_list_expression<'a, L: TemplateLanguage<'a> + ?Sized>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    node: &ExpressionNode,
+) -> TemplateParseResult<BoxedTemplateProperty<'a, Vec<String>>> {
+    expect_expression_of_type(
+        language,
+        diagnostics,
+        build_ctx,
+        node,
+        "List<String>",
+        |expression| expression.try_into_string_list(),
+    )
+}
+
+/// Builds integer property to be used as a sort key. `Timestamp` is converted
+/// to milliseconds since epoch.
+fn expect_sort_key_expression<'a, L: TemplateLanguage<'a> + ?Sized>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    build_ctx: &BuildContext<L::Property>,
+    node: &ExpressionNode,
+) -> TemplateParseResult<BoxedTemplateProperty<'a, i64>> {
+    template_parser::catch_aliases(diagnostics, node, |diagnostics, node| {
+        let expression = build_expression(language, diagnostics, build_ctx, node)?;
+        let actual_type = expression.type_name();
+        let key = if actual_type == "Timestamp" {
+            expression
+                .try_into_timestamp()
+                .map(|property| property.map(|timestamp| timestamp.timestamp.0).into_dyn())
+        } else {
+            expression.try_into_integer()
+        };
+        key.ok_or_else(|| {
+            TemplateParseError::expected_type("Integer or Timestamp", actual_type, node.span)
+        })
+    })
+}
+
 pub fn expect_stringify_expression<'a, L: TemplateLanguage<'a> + ?Sized>(
     language: &L,
     diagnostics: &mut TemplateDiagnostics,
@@ -2493,7 +3599,7 @@ mod tests {
         ");
 
         // Optional integer can be converted to boolean, and Some(0) is truthy.
-        env.add_keyword("none_i64", || literal(None));
+        env.add_keyword("none_i64", || literal(None::<i64>));
         env.add_keyword("some_i64", || literal(Some(0)));
         insta::assert_snapshot!(env.render_ok(r#"if(none_i64, true, false)"#), @"false");
         insta::assert_snapshot!(env.render_ok(r#"if(some_i64, true, false)"#), @"true");
@@ -2526,7 +3632,7 @@ mod tests {
     #[test]
     fn test_arithmetic_operation() {
         let mut env = TestTemplateEnv::new();
-        env.add_keyword("none_i64", || literal(None));
+        env.add_keyword("none_i64", || literal(None::<i64>));
         env.add_keyword("some_i64", || literal(Some(1)));
         env.add_keyword("i64_min", || literal(i64::MIN));
         env.add_keyword("i64_max", || literal(i64::MAX));
@@ -2647,118 +3753,394 @@ mod tests {
     }
 
     #[test]
-    fn test_list_method() {
+    fn test_list_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("empty", || literal(true));
+        env.add_keyword("sep", || literal("sep".to_owned()));
+
+        insta::assert_snapshot!(env.render_ok(r#""".lines().len()"#), @"0");
+        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().len()"#), @"3");
+
+        insta::assert_snapshot!(env.render_ok(r#""".lines().join("|")"#), @"");
+        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().join("|")"#), @"a|b|c");
+        // Null separator
+        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().join("\0")"#), @"a\0b\0c");
+        // Keyword as separator
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().join(sep.upper())"#),
+            @"aSEPbSEPc");
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc".lines().filter(|s| s.len() == 1)"#),
+            @"a c");
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().map(|s| s ++ s)"#),
+            @"aa bb cc");
+        // Global keyword in item template
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().map(|s| s ++ empty)"#),
+            @"atrue btrue ctrue");
+        // Global keyword in item template shadowing 'self'
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().map(|self| self ++ empty)"#),
+            @"atrue btrue ctrue");
+        // Override global keyword 'empty'
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().map(|empty| empty)"#),
+            @"a b c");
+        // Nested map operations
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().map(|s| "x\ny".lines().map(|t| s ++ t))"#),
+            @"ax ay bx by cx cy");
+        // Nested map/join operations
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().map(|s| "x\ny".lines().map(|t| s ++ t).join(",")).join(";")"#),
+            @"ax,ay;bx,by;cx,cy");
+        // Nested string operations
+        insta::assert_snapshot!(
+            env.render_ok(r#""!  a\n!b\nc\n   end".remove_suffix("end").trim_end().lines().map(|s| s.remove_prefix("!").trim_start())"#),
+            @"a b c");
+
+        // Lambda expression in alias
+        env.add_alias("identity", "|x| x");
+        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().map(identity)"#), @"a b c");
+
+        // Not a lambda expression
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(empty)"#), @r#"
+         --> 1:17
+          |
+        1 | "a".lines().map(empty)
+          |                 ^---^
+          |
+          = Expected lambda expression
+        "#);
+        // Bad lambda parameter count
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(|| "")"#), @r#"
+         --> 1:18
+          |
+        1 | "a".lines().map(|| "")
+          |                  ^
+          |
+          = Expected 1 lambda parameters
+        "#);
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(|a, b| "")"#), @r#"
+         --> 1:18
+          |
+        1 | "a".lines().map(|a, b| "")
+          |                  ^--^
+          |
+          = Expected 1 lambda parameters
+        "#);
+        // Bad lambda output
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().filter(|s| s ++ "\n")"#), @r#"
+         --> 1:24
+          |
+        1 | "a".lines().filter(|s| s ++ "\n")
+          |                        ^-------^
+          |
+          = Expected expression of type `Boolean`, but actual type is `Template`
+        "#);
+        // Error in lambda expression
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(|s| s.unknown())"#), @r#"
+         --> 1:23
+          |
+        1 | "a".lines().map(|s| s.unknown())
+          |                       ^-----^
+          |
+          = Method `unknown` doesn't exist for type `String`
+        "#);
+        // Error in lambda alias
+        env.add_alias("too_many_params", "|x, y| x");
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(too_many_params)"#), @r#"
+         --> 1:17
+          |
+        1 | "a".lines().map(too_many_params)
+          |                 ^-------------^
+          |
+          = In alias `too_many_params`
+         --> 1:2
+          |
+        1 | |x, y| x
+          |  ^--^
+          |
+          = Expected 1 lambda parameters
+        "#);
+    }
+
+    #[test]
+    fn test_string_list_intersperse_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("sep", || literal("sep".to_owned()));
+
+        insta::assert_snapshot!(env.render_ok(r#""".lines().intersperse("-").len()"#), @"0");
+        insta::assert_snapshot!(env.render_ok(r#""a".lines().intersperse("-").len()"#), @"1");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().intersperse("-").join("")"#), @"a-b-c");
+        // Result can be processed further as a list
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().intersperse(sep).map(|s| s.upper()).join(",")"#),
+            @"A,SEP,B,SEP,C");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().intersperse(1 + 2).join("")"#), @"a3b");
+    }
+
+    #[test]
+    fn test_string_list_zip_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("names", || literal(vec!["a".to_owned(), "b".to_owned()]));
+        env.add_keyword("ids", || {
+            literal(vec!["1".to_owned(), "2".to_owned(), "3".to_owned()])
+        });
+
+        insta::assert_snapshot!(
+            env.render_ok(r#"names.zip(ids).map(|p| p.first() ++ "=" ++ p.second())"#),
+            @"a=1 b=2");
+        // Truncated to the shorter list
+        insta::assert_snapshot!(env.render_ok(r#"ids.zip(names).len()"#), @"2");
+        insta::assert_snapshot!(env.render_ok(r#"names.zip("".lines()).len()"#), @"0");
+        insta::assert_snapshot!(
+            env.render_ok(r#"if(names.zip("".lines()), "non-empty", "empty")"#), @"empty");
+        // Result can be processed further as a list
+        insta::assert_snapshot!(
+            env.render_ok(r#"ids.zip(names).filter(|p| p.second() != "a").map(|p| p.first())"#),
+            @"2");
+
+        insta::assert_snapshot!(env.parse_err(r#"names.zip("a")"#), @r#"
+         --> 1:11
+          |
+        1 | names.zip("a")
+          |           ^-^
+          |
+          = Expected expression of type `List<String>`, but actual type is `String`
+        "#);
+        insta::assert_snapshot!(env.parse_err(r#"names.zip(ids).join(",")"#), @r#"
+         --> 1:16
+          |
+        1 | names.zip(ids).join(",")
+          |                ^--^
+          |
+          = Method `join` doesn't exist for type `List<Pair<String, String>>`
+        "#);
+    }
+
+    #[test]
+    fn test_list_any_all_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("bad_bool", || new_error_property::<bool>("Bad"));
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc".lines().any(|s| s.len() == 2)"#), @"true");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc".lines().any(|s| s.len() == 3)"#), @"false");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc".lines().all(|s| s.len() >= 1)"#), @"true");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc".lines().all(|s| s.len() == 1)"#), @"false");
+
+        // Empty list
+        insta::assert_snapshot!(env.render_ok(r#""".lines().any(|s| true)"#), @"false");
+        insta::assert_snapshot!(env.render_ok(r#""".lines().all(|s| false)"#), @"true");
+
+        // Short-circuit evaluation
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().any(|s| s == "a" || bad_bool)"#),
+            @"true");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().all(|s| s != "a" && bad_bool)"#),
+            @"false");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().any(|s| s == "b" || bad_bool)"#),
+            @"<Error: Bad>");
+
+        // Bad lambda output
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().all(|s| s ++ "\n")"#), @r#"
+         --> 1:21
+          |
+        1 | "a".lines().all(|s| s ++ "\n")
+          |                     ^-------^
+          |
+          = Expected expression of type `Boolean`, but actual type is `Template`
+        "#);
+    }
+
+    #[test]
+    fn test_list_count_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("bad_bool", || new_error_property::<bool>("Bad"));
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc\ndd".lines().count(|s| s.len() == 2)"#), @"2");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc".lines().count(|s| s.len() == 3)"#), @"0");
+        insta::assert_snapshot!(env.render_ok(r#""".lines().count(|s| true)"#), @"0");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().count(|s| s == "a" || bad_bool)"#),
+            @"<Error: Bad>");
+
+        // Bad lambda output
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().count(|s| s ++ "\n")"#), @r#"
+         --> 1:23
+          |
+        1 | "a".lines().count(|s| s ++ "\n")
+          |                       ^-------^
+          |
+          = Expected expression of type `Boolean`, but actual type is `Template`
+        "#);
+    }
+
+    #[test]
+    fn test_list_group_by_method() {
         let mut env = TestTemplateEnv::new();
-        env.add_keyword("empty", || literal(true));
-        env.add_keyword("sep", || literal("sep".to_owned()));
-
-        insta::assert_snapshot!(env.render_ok(r#""".lines().len()"#), @"0");
-        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().len()"#), @"3");
-
-        insta::assert_snapshot!(env.render_ok(r#""".lines().join("|")"#), @"");
-        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().join("|")"#), @"a|b|c");
-        // Null separator
-        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().join("\0")"#), @"a\0b\0c");
-        // Keyword as separator
-        insta::assert_snapshot!(
-            env.render_ok(r#""a\nb\nc".lines().join(sep.upper())"#),
-            @"aSEPbSEPc");
-
-        insta::assert_snapshot!(
-            env.render_ok(r#""a\nbb\nc".lines().filter(|s| s.len() == 1)"#),
-            @"a c");
+        env.add_keyword("bad_string", || new_error_property::<String>("Bad"));
 
         insta::assert_snapshot!(
-            env.render_ok(r#""a\nb\nc".lines().map(|s| s ++ s)"#),
-            @"aa bb cc");
-        // Global keyword in item template
-        insta::assert_snapshot!(
-            env.render_ok(r#""a\nb\nc".lines().map(|s| s ++ empty)"#),
-            @"atrue btrue ctrue");
-        // Global keyword in item template shadowing 'self'
+            env.render_ok(r#""a.rs\nb.md\nc.rs\nd".lines().group_by(|s| s.remove_prefix(s.substr(0, 2))).map(|g| g.key() ++ ":" ++ g.items().join(","))"#),
+            @"rs:a.rs,c.rs md:b.md :d");
         insta::assert_snapshot!(
-            env.render_ok(r#""a\nb\nc".lines().map(|self| self ++ empty)"#),
-            @"atrue btrue ctrue");
-        // Override global keyword 'empty'
+            env.render_ok(r#""a\nbb\nc\ndd\neee".lines().group_by(|s| s.len()).map(|g| g.first() ++ "=" ++ g.second().len()).join(";")"#),
+            @"1=2;2=2;3=1");
         insta::assert_snapshot!(
-            env.render_ok(r#""a\nb\nc".lines().map(|empty| empty)"#),
-            @"a b c");
-        // Nested map operations
+            env.render_ok(r#""a\nbb\nc".lines().group_by(|s| s.len()).len()"#),
+            @"2");
         insta::assert_snapshot!(
-            env.render_ok(r#""a\nb\nc".lines().map(|s| "x\ny".lines().map(|t| s ++ t))"#),
-            @"ax ay bx by cx cy");
-        // Nested map/join operations
+            env.render_ok(r#""a\nbb\nc".lines().group_by(|s| s.len()).filter(|g| g.items().len() > 1).map(|g| g.items())"#),
+            @"a c");
         insta::assert_snapshot!(
-            env.render_ok(r#""a\nb\nc".lines().map(|s| "x\ny".lines().map(|t| s ++ t).join(",")).join(";")"#),
-            @"ax,ay;bx,by;cx,cy");
-        // Nested string operations
+            env.render_ok(r#"if("".lines().group_by(|s| s), "some", "none")"#),
+            @"none");
         insta::assert_snapshot!(
-            env.render_ok(r#""!  a\n!b\nc\n   end".remove_suffix("end").trim_end().lines().map(|s| s.remove_prefix("!").trim_start())"#),
-            @"a b c");
-
-        // Lambda expression in alias
-        env.add_alias("identity", "|x| x");
-        insta::assert_snapshot!(env.render_ok(r#""a\nb\nc".lines().map(identity)"#), @"a b c");
+            env.render_ok(r#""a\nb".lines().group_by(|s| bad_string)"#),
+            @"<Error: Bad>");
 
-        // Not a lambda expression
-        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(empty)"#), @r#"
-         --> 1:17
+        // Bad argument count
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().group_by(|s| s, |k, xs| k)"#), @r#"
+         --> 1:22
           |
-        1 | "a".lines().map(empty)
-          |                 ^---^
+        1 | "a".lines().group_by(|s| s, |k, xs| k)
+          |                      ^--------------^
           |
-          = Expected lambda expression
+          = Function `group_by`: Expected 1 arguments
         "#);
-        // Bad lambda parameter count
-        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(|| "")"#), @r#"
-         --> 1:18
+        // Groups aren't printable
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().group_by(|s| s)"#), @r#"
+         --> 1:1
           |
-        1 | "a".lines().map(|| "")
-          |                  ^
+        1 | "a".lines().group_by(|s| s)
+          | ^-------------------------^
           |
-          = Expected 1 lambda parameters
+          = Expected expression of type `Template`, but actual type is `List<Pair<String, List<String>>>`
         "#);
-        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(|a, b| "")"#), @r#"
-         --> 1:18
+    }
+
+    #[test]
+    fn test_list_enumerate_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("bad_list", || new_error_property::<Vec<String>>("Bad"));
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb\nc".lines().enumerate().map(|e| e.index() ++ ":" ++ e.value())"#),
+            @"0:a 1:b 2:c");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc".lines().enumerate().filter(|e| e.value().len() == 1).len()"#),
+            @"2");
+        insta::assert_snapshot!(
+            env.render_ok(r#"if("".lines().enumerate(), "nonempty", "empty")"#),
+            @"empty");
+        insta::assert_snapshot!(
+            env.render_ok(r#"bad_list.enumerate().len()"#),
+            @"<Error: Bad>");
+
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().enumerate(0)"#), @r#"
+         --> 1:23
           |
-        1 | "a".lines().map(|a, b| "")
-          |                  ^--^
+        1 | "a".lines().enumerate(0)
+          |                       ^
           |
-          = Expected 1 lambda parameters
+          = Function `enumerate`: Expected 0 arguments
         "#);
-        // Bad lambda output
-        insta::assert_snapshot!(env.parse_err(r#""a".lines().filter(|s| s ++ "\n")"#), @r#"
+    }
+
+    #[test]
+    fn test_list_find_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("bad_bool", || new_error_property::<bool>("Bad"));
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc\ndd".lines().find(|s| s.len() == 2)"#),
+            @"bb");
+        insta::assert_snapshot!(
+            env.render_ok(r#"if("a\nc".lines().find(|s| s.len() == 2), "some", "none")"#),
+            @"none");
+        // Items after the first match aren't evaluated
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().find(|s| s == "a" || bad_bool)"#),
+            @"a");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().find(|s| bad_bool)"#),
+            @"<Error: Bad>");
+    }
+
+    #[test]
+    fn test_list_max_by_min_by_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("bad_int", || new_error_property::<i64>("Bad"));
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc\ndd".lines().max_by(|s| s.len())"#),
+            @"bb");
+        insta::assert_snapshot!(
+            env.render_ok(r#""bb\na\nc\ndd".lines().min_by(|s| s.len())"#),
+            @"a");
+        insta::assert_snapshot!(
+            env.render_ok(r#"if("".lines().max_by(|s| s.len()), "some", "none")"#),
+            @"none");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().min_by(|s| bad_int)"#),
+            @"<Error: Bad>");
+
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().max_by(|s| s)"#), @r#"
          --> 1:24
           |
-        1 | "a".lines().filter(|s| s ++ "\n")
-          |                        ^-------^
+        1 | "a".lines().max_by(|s| s)
+          |                        ^
           |
-          = Expected expression of type `Boolean`, but actual type is `Template`
+          = Expected expression of type `Integer or Timestamp`, but actual type is `String`
         "#);
-        // Error in lambda expression
-        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(|s| s.unknown())"#), @r#"
+    }
+
+    #[test]
+    fn test_list_partition_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("bad_bool", || new_error_property::<bool>("Bad"));
+
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc\ndd".lines().partition(|s| s.len() == 2).matched()"#),
+            @"bb dd");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nbb\nc\ndd".lines().partition(|s| s.len() == 2).unmatched()"#),
+            @"a c");
+        insta::assert_snapshot!(
+            env.render_ok(r#"
+              "a\nbb\nc".lines().partition(|s| s.len() == 2)
+                .first().len() ++ ";" ++
+              "a\nbb\nc".lines().partition(|s| s.len() == 2).second().len()
+            "#),
+            @"1;2");
+        insta::assert_snapshot!(
+            env.render_ok(r#""".lines().partition(|s| true).matched().len()"#),
+            @"0");
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\nb".lines().partition(|s| bad_bool).matched()"#),
+            @"<Error: Bad>");
+
+        insta::assert_snapshot!(env.parse_err(r#""a".lines().partition(|s| true, |xs| xs)"#), @r#"
          --> 1:23
           |
-        1 | "a".lines().map(|s| s.unknown())
-          |                       ^-----^
-          |
-          = Method `unknown` doesn't exist for type `String`
-        "#);
-        // Error in lambda alias
-        env.add_alias("too_many_params", "|x, y| x");
-        insta::assert_snapshot!(env.parse_err(r#""a".lines().map(too_many_params)"#), @r#"
-         --> 1:17
-          |
-        1 | "a".lines().map(too_many_params)
-          |                 ^-------------^
-          |
-          = In alias `too_many_params`
-         --> 1:2
-          |
-        1 | |x, y| x
-          |  ^--^
+        1 | "a".lines().partition(|s| true, |xs| xs)
+          |                       ^---------------^
           |
-          = Expected 1 lambda parameters
+          = Function `partition`: Expected 1 arguments
         "#);
     }
 
@@ -2789,6 +4171,15 @@ mod tests {
         insta::assert_snapshot!(env.render_ok(r#""".first_line()"#), @"");
         insta::assert_snapshot!(env.render_ok(r#""foo\nbar".first_line()"#), @"foo");
 
+        insta::assert_snapshot!(env.render_ok(r#""".summary_line(3)"#), @"");
+        insta::assert_snapshot!(env.render_ok(r#""foo\nbar".summary_line(3)"#), @"foo");
+        insta::assert_snapshot!(env.render_ok(r#""foobar\nbaz".summary_line(4)"#), @"foo…");
+        insta::assert_snapshot!(env.render_ok(r#""一二三四五\nx".summary_line(10)"#), @"一二三四五");
+        insta::assert_snapshot!(env.render_ok(r#""一二三四五\nx".summary_line(6)"#), @"一二…");
+        insta::assert_snapshot!(env.render_ok(r#""一二三四五\nx".summary_line(5)"#), @"一二…");
+        insta::assert_snapshot!(env.render_ok(r#""一二三".summary_line(1)"#), @"…");
+        insta::assert_snapshot!(env.render_ok(r#""一二三".summary_line(0)"#), @"");
+
         insta::assert_snapshot!(env.render_ok(r#""".lines()"#), @"");
         insta::assert_snapshot!(env.render_ok(r#""a\nb\nc\n".lines()"#), @"a b c");
 
@@ -2855,6 +4246,96 @@ mod tests {
 
         insta::assert_snapshot!(env.render_ok(r#""hello".escape_json()"#), @r#""hello""#);
         insta::assert_snapshot!(env.render_ok(r#""he \n ll \n \" o".escape_json()"#), @r#""he \n ll \n \" o""#);
+
+        insta::assert_snapshot!(env.render_ok(r#""hello".escape_template()"#), @r#""hello""#);
+        insta::assert_snapshot!(
+            env.render_ok(r#"'say "hi" ++ \n'.escape_template()"#), @r#""say \"hi\" ++ \\n""#);
+        insta::assert_snapshot!(
+            env.render_ok(r#""a\tb\nc\e\x7f".escape_template()"#), @r#""a\tb\nc\e\x7f""#);
+        // Escaped text can be embedded in another template
+        let escaped = env.render_ok(r#"'foo ++ "bar" \'.escape_template()"#);
+        insta::assert_snapshot!(env.render_ok(&format!("{escaped} ++ \"!\"")), @r#"foo ++ "bar" \!"#);
+    }
+
+    #[test]
+    fn test_string_wrap_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("paragraph", || {
+            literal(
+                "The quick brown fox jumps over the lazy dog. The quick brown\n\
+                 fox jumps over the lazy dog.\n"
+                    .to_owned(),
+            )
+        });
+        env.add_keyword("paragraphs", || {
+            literal("The quick brown fox\njumps over\n\n\nthe   lazy\n  dog.\n".to_owned())
+        });
+
+        insta::assert_snapshot!(env.render_ok("paragraph.wrap(20)"), @r"
+        The quick brown fox
+        jumps over the lazy
+        dog. The quick brown
+        fox jumps over the
+        lazy dog.
+        ");
+
+        // Paragraphs are reflowed separately, and blank lines are preserved
+        insta::assert_snapshot!(env.render_ok("paragraphs.wrap(30)"), @r"
+        The quick brown fox jumps over
+
+
+        the lazy dog.
+        ");
+
+        // Words aren't split
+        insta::assert_snapshot!(env.render_ok(r#""foo barbazqux".wrap(3)"#), @r"
+        foo
+        barbazqux
+        ");
+        insta::assert_snapshot!(env.render_ok(r#""💩💩 💩".wrap(4)"#), @r"
+        💩💩
+        💩
+        ");
+        insta::assert_snapshot!(env.render_ok(r#""".wrap(10)"#), @"");
+    }
+
+    #[test]
+    fn test_string_autolink_method() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("description", || {
+            literal("Fix crash (#123)\n\nCloses #45, refs #6.\n".to_owned())
+        });
+
+        insta::assert_snapshot!(
+            env.render_ok(r##"description.autolink('#(\d+)', "https://example.com/issues/$1")"##),
+            @r"
+        Fix crash (https://example.com/issues/123)
+
+        Closes https://example.com/issues/45, refs https://example.com/issues/6.
+        ");
+        insta::assert_snapshot!(
+            env.render_ok(r##"description.autolink('#(?<id>\d+)', "[#${id}](https://example.com/${id})")"##),
+            @r"
+        Fix crash ([#123](https://example.com/123))
+
+        Closes [#45](https://example.com/45), refs [#6](https://example.com/6).
+        ");
+        // No match
+        insta::assert_snapshot!(
+            env.render_ok(r##""no issues".autolink('#(\d+)', "$1")"##), @"no issues");
+
+        insta::assert_snapshot!(env.parse_err(r#""".autolink("(", "")"#), @r#"
+         --> 1:13
+          |
+        1 | "".autolink("(", "")
+          |             ^-^
+          |
+          = Invalid regular expression
+        regex parse error:
+            (
+            ^
+        error: unclosed group
+        "#);
     }
 
     #[test]
@@ -2902,6 +4383,13 @@ mod tests {
         insta::assert_snapshot!(env.render_ok(r#"author.name()"#), @"Test User");
         insta::assert_snapshot!(env.render_ok(r#"author.email()"#), @"test.user@example.com");
         insta::assert_snapshot!(env.render_ok(r#"author.username()"#), @"test.user");
+        insta::assert_snapshot!(env.render_ok(r#"author.name_key()"#), @"test user");
+
+        env.add_keyword("author", || {
+            literal(new_signature("  Test   User ", "test.user@example.com"))
+        });
+        insta::assert_snapshot!(env.render_ok(r#"author.name()"#), @"  Test   User ");
+        insta::assert_snapshot!(env.render_ok(r#"author.name_key()"#), @"test user");
 
         env.add_keyword("author", || {
             literal(new_signature("Another Test User", "test.user@example.com"))
@@ -3039,6 +4527,217 @@ mod tests {
           |
           = Invalid time format
         "#);
+
+        // Far past/future timestamps don't depend on the current time
+        env.add_keyword("t_future", || literal(new_timestamp(32_503_680_000_000, 0)));
+        insta::assert_snapshot!(env.render_ok("t0.is_future()"), @"false");
+        insta::assert_snapshot!(env.render_ok("t_future.is_future()"), @"true");
+
+        // Buckets are relative to the current date
+        let day_msec = 24 * 60 * 60 * 1000;
+        let now = Timestamp::now();
+        let days_ago = move |days: i64| Timestamp {
+            timestamp: MillisSinceEpoch(now.timestamp.0 - days * day_msec),
+            tz_offset: now.tz_offset,
+        };
+        env.add_keyword("t_today", move || literal(days_ago(0)));
+        env.add_keyword("t_yesterday", move || literal(days_ago(1)));
+        env.add_keyword("t_older", move || literal(days_ago(30)));
+        insta::assert_snapshot!(env.render_ok("t_today.relative_bucket()"), @"today");
+        insta::assert_snapshot!(env.render_ok("t_yesterday.relative_bucket()"), @"yesterday");
+        insta::assert_snapshot!(env.render_ok("t_older.relative_bucket()"), @"older");
+        insta::assert_snapshot!(env.render_ok("t0.relative_bucket()"), @"older");
+        insta::assert_snapshot!(env.render_ok("t_future.relative_bucket()"), @"today");
+
+        // Labels can be overridden
+        env.add_alias("yesterday_label", r#""gestern""#);
+        insta::assert_snapshot!(
+            env.render_ok(r#"t_today.relative_bucket(today="heute")"#), @"heute");
+        insta::assert_snapshot!(
+            env.render_ok(r#"t_yesterday.relative_bucket(yesterday=yesterday_label)"#),
+            @"gestern");
+        insta::assert_snapshot!(
+            env.render_ok(r#"t_older.relative_bucket("a", "b", "c", "d")"#), @"d");
+        insta::assert_snapshot!(env.parse_err(r#"t0.relative_bucket(older=t0)"#), @r"
+         --> 1:26
+          |
+        1 | t0.relative_bucket(older=t0)
+          |                          ^^
+          |
+          = Expected string literal
+        ");
+    }
+
+    #[test]
+    fn test_timestamp_quarter_method() {
+        let mut env = TestTemplateEnv::new();
+        // 2024-03-31T23:30:00Z
+        let q1_end_utc = 1_711_927_800_000;
+        env.add_keyword("t_utc", move || literal(new_timestamp(q1_end_utc, 0)));
+        env.add_keyword("t_east", move || literal(new_timestamp(q1_end_utc, 60)));
+        env.add_keyword("t_west", move || literal(new_timestamp(q1_end_utc, -60)));
+        // 2024-12-31T23:30:00Z
+        let q4_end_utc = 1_735_687_800_000;
+        env.add_keyword("t_q4_utc", move || literal(new_timestamp(q4_end_utc, 0)));
+        env.add_keyword("t_q4_east", move || literal(new_timestamp(q4_end_utc, 60)));
+        // 1970-01-01T00:00:00Z
+        env.add_keyword("t0_utc", || literal(new_timestamp(0, 0)));
+        env.add_keyword("t0_west", || literal(new_timestamp(0, -60)));
+
+        insta::assert_snapshot!(env.render_ok("t_utc.quarter()"), @"1");
+        insta::assert_snapshot!(env.render_ok("t_east.quarter()"), @"2");
+        insta::assert_snapshot!(env.render_ok("t_west.quarter()"), @"1");
+        insta::assert_snapshot!(env.render_ok("t_q4_utc.quarter()"), @"4");
+        insta::assert_snapshot!(env.render_ok("t_q4_east.quarter()"), @"1");
+        insta::assert_snapshot!(env.render_ok("t0_utc.quarter()"), @"1");
+        insta::assert_snapshot!(env.render_ok("t0_west.quarter()"), @"4");
+        insta::assert_snapshot!(env.render_ok("t_east.utc().quarter()"), @"1");
+    }
+
+    #[test]
+    fn test_timestamp_min_max_method() {
+        let mut env = TestTemplateEnv::new();
+        // 2001-02-03T04:05:06Z and 2001-02-03T05:05:06Z. The earlier one has
+        // later local time.
+        let early = 981_173_106_000;
+        let late = 981_176_706_000;
+        env.add_keyword("t_early", move || literal(new_timestamp(early, 9 * 60)));
+        env.add_keyword("t_late", move || literal(new_timestamp(late, -60)));
+
+        // Compared by instant, not by local time
+        insta::assert_snapshot!(
+            env.render_ok("t_early.min(t_late)"), @"2001-02-03 13:05:06.000 +09:00");
+        insta::assert_snapshot!(
+            env.render_ok("t_late.min(t_early)"), @"2001-02-03 13:05:06.000 +09:00");
+        insta::assert_snapshot!(
+            env.render_ok("t_early.max(t_late)"), @"2001-02-03 04:05:06.000 -01:00");
+        insta::assert_snapshot!(
+            env.render_ok("t_late.max(t_early)"), @"2001-02-03 04:05:06.000 -01:00");
+
+        insta::assert_snapshot!(env.parse_err(r#"t_early.min("2001-02-03")"#), @r#"
+         --> 1:13
+          |
+        1 | t_early.min("2001-02-03")
+          |             ^----------^
+          |
+          = Expected expression of type `Timestamp`, but actual type is `String`
+        "#);
+    }
+
+    #[test]
+    fn test_timestamp_iso_week_method() {
+        let mut env = TestTemplateEnv::new();
+        // 2024-12-30T00:30:00Z (Monday of ISO week 1 of 2025)
+        let w1_start_utc = 1_735_518_600_000;
+        env.add_keyword("t_w1_utc", move || literal(new_timestamp(w1_start_utc, 0)));
+        env.add_keyword("t_w1_west", move || {
+            literal(new_timestamp(w1_start_utc, -60))
+        });
+        // 2021-01-03T12:00:00Z (Sunday of ISO week 53 of 2020)
+        let w53_end_utc = 1_609_675_200_000;
+        env.add_keyword("t_w53_utc", move || literal(new_timestamp(w53_end_utc, 0)));
+        // 2023-01-01T12:00:00Z (Sunday of ISO week 52 of 2022)
+        let w52_end_utc = 1_672_574_400_000;
+        env.add_keyword("t_w52_utc", move || literal(new_timestamp(w52_end_utc, 0)));
+
+        insta::assert_snapshot!(env.render_ok("t_w1_utc.iso_week()"), @"1");
+        insta::assert_snapshot!(
+            env.render_ok(r#"t_w1_utc.format("%G-W%V") ++ " " ++ t_w1_utc.format("%Y")"#),
+            @"2025-W01 2024");
+        insta::assert_snapshot!(env.render_ok("t_w1_west.iso_week()"), @"52");
+        insta::assert_snapshot!(env.render_ok("t_w1_west.utc().iso_week()"), @"1");
+        insta::assert_snapshot!(env.render_ok("t_w53_utc.iso_week()"), @"53");
+        insta::assert_snapshot!(env.render_ok("t_w52_utc.iso_week()"), @"52");
+    }
+
+    #[test]
+    fn test_timestamp_weekday_name_method() {
+        let mut env = TestTemplateEnv::new();
+        // 2024-12-30T00:30:00Z (Monday)
+        let monday_utc = 1_735_518_600_000;
+        env.add_keyword("t_utc", move || literal(new_timestamp(monday_utc, 0)));
+        env.add_keyword("t_west", move || literal(new_timestamp(monday_utc, -60)));
+        // 2021-01-03T12:00:00Z (Sunday)
+        let sunday_utc = 1_609_675_200_000;
+        env.add_keyword("t_sunday", move || literal(new_timestamp(sunday_utc, 0)));
+
+        insta::assert_snapshot!(env.render_ok("t_utc.weekday_name()"), @"Monday");
+        insta::assert_snapshot!(env.render_ok("t_west.weekday_name()"), @"Sunday");
+        insta::assert_snapshot!(env.render_ok("t_west.utc().weekday_name()"), @"Monday");
+        insta::assert_snapshot!(env.render_ok("t_sunday.weekday_name()"), @"Sunday");
+        insta::assert_snapshot!(
+            env.render_ok(r#"t_sunday.weekday_name(names="Mo Di Mi Do Fr Sa So")"#), @"So");
+
+        insta::assert_snapshot!(env.parse_err(r#"t_utc.weekday_name(names="Mo Di")"#), @r#"
+         --> 1:26
+          |
+        1 | t_utc.weekday_name(names="Mo Di")
+          |                          ^-----^
+          |
+          = Expected 7 weekday names starting from Monday
+        "#);
+        insta::assert_snapshot!(env.parse_err(r#"t_utc.weekday_name("Mo")"#), @r#"
+         --> 1:20
+          |
+        1 | t_utc.weekday_name("Mo")
+          |                    ^--^
+          |
+          = Expected 7 weekday names starting from Monday
+        "#);
+    }
+
+    #[test]
+    fn test_timestamp_business_days_since_method() {
+        let mut env = TestTemplateEnv::new();
+        // 2024-12-27T12:00:00Z (Friday)
+        let friday_utc = 1_735_300_800_000;
+        env.add_keyword("t_fri", move || literal(new_timestamp(friday_utc, 0)));
+        // 2024-12-28T12:00:00Z (Saturday)
+        let saturday_utc = 1_735_387_200_000;
+        env.add_keyword("t_sat", move || literal(new_timestamp(saturday_utc, 0)));
+        // 2024-12-30T00:30:00Z (Monday)
+        let monday_utc = 1_735_518_600_000;
+        env.add_keyword("t_mon", move || literal(new_timestamp(monday_utc, 0)));
+        env.add_keyword("t_mon_west", move || {
+            literal(new_timestamp(monday_utc, -60))
+        });
+        // 2025-01-10T12:00:00Z (Friday, two weeks later)
+        let next_friday_utc = 1_736_510_400_000;
+        env.add_keyword("t_fri2", move || literal(new_timestamp(next_friday_utc, 0)));
+
+        insta::assert_snapshot!(env.render_ok("t_fri.business_days_since(t_fri)"), @"0");
+        insta::assert_snapshot!(env.render_ok("t_sat.business_days_since(t_fri)"), @"0");
+        insta::assert_snapshot!(env.render_ok("t_mon.business_days_since(t_fri)"), @"1");
+        insta::assert_snapshot!(env.render_ok("t_mon.business_days_since(t_sat)"), @"1");
+        insta::assert_snapshot!(env.render_ok("t_fri.business_days_since(t_mon)"), @"-1");
+        // Sunday in its own timezone
+        insta::assert_snapshot!(env.render_ok("t_mon_west.business_days_since(t_fri)"), @"0");
+        insta::assert_snapshot!(env.render_ok("t_fri2.business_days_since(t_fri)"), @"10");
+        insta::assert_snapshot!(env.render_ok("t_fri2.business_days_since(t_mon)"), @"9");
+
+        insta::assert_snapshot!(env.parse_err(r#"t_fri.business_days_since("2024-12-27")"#), @r#"
+         --> 1:27
+          |
+        1 | t_fri.business_days_since("2024-12-27")
+          |                           ^----------^
+          |
+          = Expected expression of type `Timestamp`, but actual type is `String`
+        "#);
+    }
+
+    #[test]
+    fn test_timestamp_unix_millis_method() {
+        let mut env = TestTemplateEnv::new();
+        // 2024-12-30T00:30:00.123Z
+        let millis = 1_735_518_600_123;
+        env.add_keyword("t_utc", move || literal(new_timestamp(millis, 0)));
+        env.add_keyword("t_east", move || literal(new_timestamp(millis, 540)));
+        env.add_keyword("t_epoch", move || literal(new_timestamp(0, 0)));
+
+        insta::assert_snapshot!(env.render_ok("t_utc.unix_millis()"), @"1735518600123");
+        insta::assert_snapshot!(env.render_ok("t_east.unix_millis()"), @"1735518600123");
+        insta::assert_snapshot!(env.render_ok("t_utc.unix_millis() % 1000"), @"123");
+        insta::assert_snapshot!(env.render_ok("t_epoch.unix_millis()"), @"0");
     }
 
     #[test]
@@ -3297,6 +4996,40 @@ mod tests {
             @r"]8;;http://example.com\Example]8;;\");
     }
 
+    #[test]
+    fn test_hyperlink_function() {
+        let mut env = TestTemplateEnv::new();
+        env.add_keyword("url", || literal("http://example.com/\x1b".to_owned()));
+
+        insta::assert_snapshot!(
+            env.render_ok(r#"hyperlink("http://example.com", "Example")"#),
+            @r"]8;;http://example.com\Example]8;;\");
+        insta::assert_snapshot!(
+            env.render_ok(r#"hyperlink("http://example.com/" ++ 1, label("error", "Example"))"#),
+            @r"]8;;http://example.com/1\Example]8;;\");
+        // Control characters in URL are removed
+        insta::assert_snapshot!(
+            env.render_ok(r#"hyperlink(url, "Example")"#),
+            @r"]8;;http://example.com/\Example]8;;\");
+        // Escape sequences are preserved through buffered output
+        insta::assert_snapshot!(
+            env.render_ok(r#"indent("> ", hyperlink("http://example.com", "Example"))"#),
+            @r"]8;;http://example.com\> Example]8;;\");
+        // Plain-text conversion drops escape sequences
+        insta::assert_snapshot!(
+            env.render_ok(r#"stringify(hyperlink("http://example.com", "Example"))"#),
+            @"Example");
+
+        insta::assert_snapshot!(env.parse_err(r#"hyperlink("http://example.com")"#), @r#"
+         --> 1:11
+          |
+        1 | hyperlink("http://example.com")
+          |           ^------------------^
+          |
+          = Function `hyperlink`: Expected 2 arguments
+        "#);
+    }
+
     #[test]
     fn test_stringify_function() {
         let mut env = TestTemplateEnv::new();
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::error;
+use std::fmt::Write as _;
 use std::mem;
 use std::sync::LazyLock;
 
@@ -652,6 +653,28 @@ pub fn parse<'i>(
     dsl_util::expand_aliases(node, aliases_map)
 }
 
+/// Quotes the given `text` as a double-quoted string literal which evaluates
+/// to the original text.
+pub fn escape_string_literal(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str(r#"\""#),
+            '\\' => quoted.push_str(r"\\"),
+            '\t' => quoted.push_str(r"\t"),
+            '\r' => quoted.push_str(r"\r"),
+            '\n' => quoted.push_str(r"\n"),
+            '\0' => quoted.push_str(r"\0"),
+            '\x1b' => quoted.push_str(r"\e"),
+            c if c.is_ascii_control() => write!(quoted, r"\x{:02x}", u32::from(c)).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 /// Unwraps inner value if the given `node` is a string literal.
 pub fn expect_string_literal<'a>(node: &'a ExpressionNode<'_>) -> TemplateParseResult<&'a str> {
     catch_aliases_no_diagnostics(node, |node| match &node.kind {
@@ -1088,6 +1111,14 @@ mod tests {
             Err(TemplateParseErrorKind::SyntaxError),
         );
 
+        // Escaped string literal should be parsed back to the original text
+        for text in ["", "a\"b\\c", "\t\r\n\0\x1b\x7f", "'x' ++ y.z()", "日本語"] {
+            assert_eq!(
+                parse_into_kind(&escape_string_literal(text)),
+                Ok(ExpressionKind::String(text.to_owned())),
+            );
+        }
+
         // Single-quoted raw string
         assert_eq!(
             parse_into_kind(r#" '' "#),
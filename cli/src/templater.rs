@@ -127,6 +127,20 @@ impl Template for Email {
 // bounded to 0.
 pub type SizeHint = (usize, Option<usize>);
 
+/// List element paired with its 0-based position in the list.
+#[derive(Clone, Debug)]
+pub struct Indexed<T> {
+    pub index: usize,
+    pub value: T,
+}
+
+/// Pair of list elements taken from the same position of two lists.
+#[derive(Clone, Debug)]
+pub struct Pair<T, U> {
+    pub first: T,
+    pub second: U,
+}
+
 impl Template for String {
     fn format(&self, formatter: &mut TemplateFormatter) -> io::Result<()> {
         write!(formatter, "{self}")
@@ -214,6 +228,38 @@ impl<T: Template> Template for RawEscapeSequenceTemplate<T> {
     }
 }
 
+/// Renders contents as a terminal hyperlink (OSC 8) to the given URL.
+///
+/// If the output doesn't support escape sequences, only the contents are
+/// rendered.
+pub struct HyperlinkTemplate<P, T> {
+    pub url: P,
+    pub content: T,
+}
+
+impl<P, T> Template for HyperlinkTemplate<P, T>
+where
+    P: TemplateProperty<Output = String>,
+    T: Template,
+{
+    fn format(&self, formatter: &mut TemplateFormatter) -> io::Result<()> {
+        let url = match self.url.extract() {
+            Ok(url) => url,
+            Err(err) => return formatter.handle_error(err),
+        };
+        // Control characters would terminate the escape sequence early.
+        let url: String = url.chars().filter(|c| !c.is_control()).collect();
+        if let Some(mut raw) = formatter.raw_if_color()? {
+            raw.write_all(format!("\x1b]8;;{url}\x1b\\").as_bytes())?;
+        }
+        self.content.format(formatter)?;
+        if let Some(mut raw) = formatter.raw_if_color()? {
+            raw.write_all(b"\x1b]8;;\x1b\\")?;
+        }
+        Ok(())
+    }
+}
+
 /// Renders contents in order, and returns the first non-empty output.
 pub struct CoalesceTemplate<T>(pub Vec<T>);
 
@@ -367,6 +413,7 @@ tuple_impls! {
     (0 T0, 1 T1)
     (0 T0, 1 T1, 2 T2)
     (0 T0, 1 T1, 2 T2, 3 T3)
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4)
 }
 
 pub type BoxedTemplateProperty<'a, O> = Box<dyn TemplateProperty<Output = O> + 'a>;
@@ -786,6 +833,10 @@ impl<'a> TemplateFormatter<'a> {
         self.formatter.raw()
     }
 
+    pub fn raw_if_color(&mut self) -> io::Result<Option<Box<dyn Write + '_>>> {
+        self.formatter.raw_if_color()
+    }
+
     pub fn labeled<S: AsRef<str>>(
         &mut self,
         label: S,
@@ -501,6 +501,41 @@ pub fn wrap_bytes(text: &[u8], width: usize) -> Vec<&[u8]> {
     split_lines
 }
 
+/// Reflows paragraphs to fit in the given width.
+///
+/// Lines in each paragraph are joined, and then wrapped by `wrap_bytes()`.
+/// Paragraphs are separated by blank lines, which are preserved as is.
+pub fn reflow_paragraphs(text: &str, width: usize) -> String {
+    fn flush_paragraph(output: &mut String, words: &mut Vec<&str>, width: usize) {
+        if words.is_empty() {
+            return;
+        }
+        let joined = words.join(" ");
+        for line in wrap_bytes(joined.as_bytes(), width) {
+            // Lines are split at ASCII space, so they are still valid UTF-8.
+            output.push_str(std::str::from_utf8(line).unwrap());
+            output.push('\n');
+        }
+        words.clear();
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut words = Vec::new();
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            flush_paragraph(&mut output, &mut words, width);
+            output.push('\n');
+        } else {
+            words.extend(line.split_whitespace());
+        }
+    }
+    flush_paragraph(&mut output, &mut words, width);
+    if !text.ends_with('\n') {
+        output.pop();
+    }
+    output
+}
+
 /// Wraps lines at the given width preserving labels.
 ///
 /// `textwrap::wrap()` can also process text containing ANSI escape sequences.
@@ -1,5 +1,6 @@
 use std::sync::LazyLock;
 
+use chrono::Datelike as _;
 use chrono::format::StrftimeItems;
 use jj_lib::backend::Timestamp;
 use jj_lib::backend::TimestampOutOfRange;
@@ -68,3 +69,72 @@ pub fn format_duration(
         .map_err(|_: chrono::OutOfRangeError| TimestampOutOfRange)?;
     Ok(format.convert(duration))
 }
+
+/// Coarse distance of a timestamp from the current date.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RelativeBucket {
+    Today,
+    Yesterday,
+    ThisWeek,
+    Older,
+}
+
+/// Classifies the `timestamp` by calendar date in the time zone of `now`.
+///
+/// Timestamps in the future are classified as `Today`.
+pub fn relative_bucket(
+    timestamp: &Timestamp,
+    now: &Timestamp,
+) -> Result<RelativeBucket, TimestampOutOfRange> {
+    let now = now.to_datetime()?;
+    let today = now.date_naive();
+    let date = timestamp
+        .to_datetime()?
+        .with_timezone(now.offset())
+        .date_naive();
+    let bucket = match (today - date).num_days() {
+        ..=0 => RelativeBucket::Today,
+        1 => RelativeBucket::Yesterday,
+        _ if date.iso_week() == today.iso_week() => RelativeBucket::ThisWeek,
+        _ => RelativeBucket::Older,
+    };
+    Ok(bucket)
+}
+
+/// Returns the calendar quarter (1-4) of the `timestamp` in its own time zone.
+pub fn quarter(timestamp: &Timestamp) -> Result<u32, TimestampOutOfRange> {
+    let month = timestamp.to_datetime()?.month();
+    Ok((month - 1) / 3 + 1)
+}
+
+/// Returns the ISO 8601 week number (1-53) of the `timestamp` in its own time
+/// zone.
+pub fn iso_week(timestamp: &Timestamp) -> Result<u32, TimestampOutOfRange> {
+    Ok(timestamp.to_datetime()?.iso_week().week())
+}
+
+/// Returns the day of the week of the `timestamp` in its own time zone,
+/// counted from Monday (0) to Sunday (6).
+pub fn weekday(timestamp: &Timestamp) -> Result<u32, TimestampOutOfRange> {
+    Ok(timestamp.to_datetime()?.weekday().num_days_from_monday())
+}
+
+/// Returns the number of weekdays (Monday to Friday) after the date of `from`
+/// up to and including the date of `to`. Each date is evaluated in the time
+/// zone of its own timestamp. The result is negative if `to` is earlier than
+/// `from`.
+pub fn business_days_between(from: &Timestamp, to: &Timestamp) -> Result<i64, TimestampOutOfRange> {
+    let from = from.to_datetime()?.date_naive();
+    let to = to.to_datetime()?.date_naive();
+    let (start, end, sign) = if from <= to {
+        (from, to, 1)
+    } else {
+        (to, from, -1)
+    };
+    let days = (end - start).num_days();
+    let start_weekday = i64::from(start.weekday().num_days_from_monday());
+    let rest_count = (1..=days % 7)
+        .filter(|i| (start_weekday + i) % 7 < 5)
+        .count();
+    Ok(sign * (days / 7 * 5 + rest_count as i64))
+}
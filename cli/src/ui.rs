@@ -769,7 +769,8 @@ fn format_error_with_sources(err: &dyn error::Error) -> impl fmt::Display + use<
     iter::successors(Some(err), |&err| err.source()).format(": ")
 }
 
-fn term_width() -> Option<u16> {
+/// Returns the width of the terminal, or the value of `$COLUMNS` if set.
+pub fn term_width() -> Option<u16> {
     if let Some(cols) = env::var("COLUMNS").ok().and_then(|s| s.parse().ok()) {
         Some(cols)
     } else {
@@ -996,6 +996,7 @@ fn test_bookmark_forget_fetched_bookmark() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: feature1@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1014,6 +1015,7 @@ fn test_bookmark_forget_fetched_bookmark() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: feature1@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1045,6 +1047,7 @@ fn test_bookmark_forget_fetched_bookmark() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: feature1@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1163,6 +1166,7 @@ fn test_bookmark_track_untrack() {
     bookmark: feature1@origin [new] untracked
     bookmark: feature2@origin [new] untracked
     bookmark: main@origin     [new] untracked
+    0 refs updated, 3 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1252,6 +1256,7 @@ fn test_bookmark_track_untrack() {
     bookmark: feature1@origin [updated] untracked
     bookmark: feature2@origin [updated] untracked
     bookmark: main@origin     [updated] tracked
+    3 refs updated, 0 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1294,6 +1299,7 @@ fn test_bookmark_track_untrack() {
     bookmark: feature3@origin [new] tracked
     bookmark: main@origin     [updated] tracked
     Abandoned 1 commits that are no longer reachable.
+    3 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1381,6 +1387,7 @@ fn test_bookmark_track_untrack_patterns() {
     ------- stderr -------
     bookmark: feature1@origin [new] untracked
     bookmark: feature2@origin [new] untracked
+    0 refs updated, 2 new, 0 deleted
     [EOF]
     ");
 
@@ -2272,6 +2279,13 @@ fn test_bookmark_list_conflicted() {
       + kkmpptxz a82129fb (empty) b
     [EOF]
     ");
+
+    let template = r#"name ++ ": " ++ conflict_side_count ++ "\n""#;
+    insta::assert_snapshot!(work_dir.run_jj(["bookmark", "list", "-T", template]), @r"
+    bar: 1
+    foo: 2
+    [EOF]
+    ");
 }
 
 #[test]
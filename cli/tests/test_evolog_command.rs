@@ -601,3 +601,44 @@ fn test_evolog_reverse_with_graph() {
     [EOF]
     ");
 }
+
+#[test]
+fn test_evolog_predecessors_template() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.run_jj(["describe", "-m", "first"]).success();
+    work_dir.write_file("file1", "foo\n");
+    work_dir.run_jj(["describe", "-m", "second"]).success();
+    work_dir.run_jj(["new", "-m", "other"]).success();
+    work_dir.run_jj(["squash", "-m", "squashed"]).success();
+
+    let template = r#"
+    commit_id.short() ++ " " ++ description.first_line()
+      ++ " <- [" ++ predecessors.map(|c| c.commit_id().short()).join(", ") ++ "]\n"
+    "#;
+    let output = work_dir.run_jj(["evolog", "--no-graph", "-r@-", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    9365ea1367a6 squashed <- [629905326d20, 9be777f2756f]
+    -- operation c19fa106605f (2001-02-03 08:05:11) squash commits into 629905326d20a6a6afcca3042e3ee056060a2eab
+    9be777f2756f other <- []
+    -- operation c95e2c2dffd2 (2001-02-03 08:05:10) new empty commit
+    629905326d20 second <- [5878cbe03cdf]
+    -- operation 1a9edff0ddf4 (2001-02-03 08:05:09) describe commit 5878cbe03cdf599c9353e5a1a52a01f4c5e0e0fa
+    5878cbe03cdf first <- [68a505386f93]
+    -- operation b5008091fb83 (2001-02-03 08:05:09) snapshot working copy
+    68a505386f93 first <- [e8849ae12c70]
+    -- operation 75545f7ff2df (2001-02-03 08:05:08) describe commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    e8849ae12c70  <- []
+    -- operation 8f47435a3990 (2001-02-03 08:05:07) add workspace 'default'
+    [EOF]
+    ");
+
+    // Works outside evolog too; the root commit has no predecessors
+    let output = work_dir.run_jj(["log", "--no-graph", "-rroot()", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    000000000000  <- []
+    [EOF]
+    ");
+}
@@ -669,6 +669,7 @@ fn test_git_colocated_fetch_deleted_or_moved_bookmark() {
     bookmark: B_to_delete@origin [deleted] untracked
     bookmark: C_to_move@origin   [updated] tracked
     Abandoned 2 commits that are no longer reachable.
+    1 ref updated, 0 new, 1 deleted
     [EOF]
     ");
     // "original C" and "B_to_delete" are abandoned, as the corresponding bookmarks
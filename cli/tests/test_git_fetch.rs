@@ -139,6 +139,7 @@ fn test_git_fetch_single_remote() {
     ------- stderr -------
     Hint: Fetching from the only existing remote: rem1
     bookmark: rem1@rem1 [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -148,6 +149,44 @@ fn test_git_fetch_single_remote() {
     ");
 }
 
+#[test]
+fn test_git_fetch_import_stats_summary() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = add_git_remote(&test_env, &work_dir, "origin");
+    let to_update_id = add_commit_to_branch(&git_repo, "to-update");
+    add_commit_to_branch(&git_repo, "to-delete");
+    work_dir.run_jj(["git", "fetch"]).success();
+
+    // Update one bookmark, delete another, and add a new one
+    git::add_commit(
+        &git_repo,
+        "refs/heads/to-update",
+        "to-update",
+        b"updated",
+        "message",
+        &[to_update_id],
+    );
+    git_repo
+        .find_reference("refs/heads/to-delete")
+        .unwrap()
+        .delete()
+        .unwrap();
+    add_commit_to_branch(&git_repo, "added");
+    let output = work_dir.run_jj(["git", "fetch"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: added@origin     [new] tracked
+    bookmark: to-delete@origin [deleted] untracked
+    bookmark: to-update@origin [updated] tracked
+    Abandoned 1 commits that are no longer reachable.
+    1 ref updated, 1 new, 1 deleted
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_fetch_single_remote_all_remotes_flag() {
     let test_env = TestEnvironment::default();
@@ -233,6 +272,7 @@ fn test_git_fetch_with_glob() {
     ------- stderr -------
     bookmark: rem1@rem1 [new] untracked
     bookmark: rem2@rem2 [new] untracked
+    0 refs updated, 2 new, 0 deleted
     [EOF]
     ");
 }
@@ -254,6 +294,7 @@ fn test_git_fetch_with_glob_and_exact_match() {
     bookmark: origin@origin [new] untracked
     bookmark: rem1@rem1     [new] untracked
     bookmark: rem2@rem2     [new] untracked
+    0 refs updated, 3 new, 0 deleted
     [EOF]
     ");
 }
@@ -273,6 +314,7 @@ fn test_git_fetch_with_glob_from_config() {
     ------- stderr -------
     bookmark: rem1@rem1 [new] untracked
     bookmark: rem2@rem2 [new] untracked
+    0 refs updated, 2 new, 0 deleted
     [EOF]
     ");
 }
@@ -372,6 +414,7 @@ fn test_git_fetch_nonexistent_remote() {
     ------- stderr -------
     Warning: No git remotes matching 'rem2'
     bookmark: rem1@rem1 [new] untracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -393,6 +436,7 @@ fn test_git_fetch_nonexistent_remote_from_config() {
     ------- stderr -------
     Warning: No git remotes matching 'rem2'
     bookmark: rem1@rem1 [new] untracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -675,6 +719,7 @@ fn test_git_fetch_all() {
     bookmark: a2@origin     [new] tracked
     bookmark: b@origin      [new] tracked
     bookmark: trunk1@origin [new] tracked
+    0 refs updated, 4 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&target_dir), @r"
@@ -753,6 +798,7 @@ fn test_git_fetch_all() {
     bookmark: b@origin      [updated] tracked
     bookmark: trunk2@origin [new] tracked
     Abandoned 2 commits that are no longer reachable.
+    3 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&target_dir), @r"
@@ -847,6 +893,7 @@ fn test_git_fetch_some_of_many_bookmarks() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: b@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -869,6 +916,7 @@ fn test_git_fetch_some_of_many_bookmarks() {
     ------- stderr -------
     bookmark: a1@origin [new] tracked
     bookmark: a2@origin [new] tracked
+    0 refs updated, 2 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -942,6 +990,7 @@ fn test_git_fetch_some_of_many_bookmarks() {
     bookmark: a1@origin [updated] tracked
     bookmark: b@origin  [updated] tracked
     Abandoned 1 commits that are no longer reachable.
+    2 refs updated, 0 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -980,6 +1029,7 @@ fn test_git_fetch_some_of_many_bookmarks() {
     ------- stderr -------
     bookmark: a2@origin [updated] tracked
     Abandoned 1 commits that are no longer reachable.
+    1 ref updated, 0 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -1049,6 +1099,7 @@ fn test_git_fetch_bookmarks_some_missing() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: origin@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1068,6 +1119,7 @@ fn test_git_fetch_bookmarks_some_missing() {
     bookmark: rem1@rem1 [new] tracked
     bookmark: rem2@rem2 [new] tracked
     bookmark: rem3@rem3 [new] tracked
+    0 refs updated, 3 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1170,6 +1222,7 @@ fn test_git_fetch_undo() {
     ------- stderr -------
     bookmark: a1@origin [new] tracked
     bookmark: b@origin  [new] tracked
+    0 refs updated, 2 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -1199,6 +1252,7 @@ fn test_git_fetch_undo() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: b@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -1253,6 +1307,7 @@ fn test_fetch_undo_what() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: b@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&work_dir), @r#"
@@ -1358,6 +1413,7 @@ fn test_git_fetch_remove_fetch() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     bookmark: origin@origin [new] tracked
+    0 refs updated, 1 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
@@ -1452,6 +1508,7 @@ fn test_git_fetch_removed_bookmark() {
     bookmark: a2@origin     [new] tracked
     bookmark: b@origin      [new] tracked
     bookmark: trunk1@origin [new] tracked
+    0 refs updated, 4 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -1498,6 +1555,7 @@ fn test_git_fetch_removed_bookmark() {
     ------- stderr -------
     bookmark: a2@origin [deleted] untracked
     Abandoned 1 commits that are no longer reachable.
+    0 refs updated, 0 new, 1 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -1550,6 +1608,7 @@ fn test_git_fetch_removed_parent_bookmark() {
     bookmark: a2@origin     [new] tracked
     bookmark: b@origin      [new] tracked
     bookmark: trunk1@origin [new] tracked
+    0 refs updated, 4 new, 0 deleted
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
@@ -1581,6 +1640,7 @@ fn test_git_fetch_removed_parent_bookmark() {
     bookmark: a1@origin     [deleted] untracked
     bookmark: trunk1@origin [deleted] untracked
     Abandoned 1 commits that are no longer reachable.
+    0 refs updated, 0 new, 2 deleted
     Warning: No branch matching `master` found on any specified/configured remote
     [EOF]
     ");
@@ -1348,6 +1348,7 @@ fn test_op_diff() {
     bookmark: bookmark-2@origin [updated] untracked
     bookmark: bookmark-3@origin [deleted] untracked
     Abandoned 1 commits that are no longer reachable.
+    2 refs updated, 0 new, 1 deleted
     [EOF]
     ");
     let output = work_dir.run_jj(["op", "diff"]);
@@ -2341,6 +2342,7 @@ fn test_op_show() {
     bookmark: bookmark-2@origin [updated] untracked
     bookmark: bookmark-3@origin [deleted] untracked
     Abandoned 1 commits that are no longer reachable.
+    2 refs updated, 0 new, 1 deleted
     [EOF]
     ");
     let output = work_dir.run_jj(["op", "show"]);
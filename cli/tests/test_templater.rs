@@ -57,7 +57,7 @@ fn test_templater_parse_error() {
       | ^-------^
       |
       = Keyword `conflicts` doesn't exist
-    Hint: Did you mean `conflict`, `conflicting`?
+    Hint: Did you mean `conflict`, `conflict_types`, `conflicting`?
     [EOF]
     [exit status: 1]
     ");
@@ -96,7 +96,7 @@ fn test_templater_parse_error() {
       |                    ^^
       |
       = Keyword `se` doesn't exist
-    Hint: Did you mean `s`, `self`?
+    Hint: Did you mean `has_description`, `is_ancestor_of`, `is_descendant_of`, `is_merge`, `s`, `self`, `signature_summary`, `signed_by_key`, `signed_off_by_count`?
     [EOF]
     [exit status: 1]
     "#);
@@ -195,6 +195,59 @@ fn test_templater_upper_lower() {
         render(r#""Hello".upper() ++ "Hello".lower()"#), @"HELLOhello[EOF]");
 }
 
+#[test]
+fn test_templater_hyperlink() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let template =
+        r#"hyperlink("https://example.com/commit/" ++ commit_id, commit_id.short(4)) ++ "\n""#;
+
+    // Hyperlink is emitted along with colors
+    let output = get_colored_template_output(&work_dir, "@", template);
+    insta::assert_snapshot!(output, @r"
+    ]8;;https://example.com/commit/e8849ae12c709f2321908879bc724fdb2ab8a781\[38;5;4me884[39m]8;;\
+    [EOF]
+    ");
+
+    // Only the text is emitted if colors are disabled
+    let output = get_template_output(&work_dir, "@", template);
+    insta::assert_snapshot!(output, @r"
+    e884
+    [EOF]
+    ");
+
+    // Hyperlink survives word wrapping
+    let output = work_dir.run_jj([
+        "log",
+        "--color=always",
+        "--config=ui.log-word-wrap=true",
+        "-r@",
+        "-T",
+        template,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    [1m[38;5;2m@[0m  ]8;;https://example.com/commit/e8849ae12c709f2321908879bc724fdb2ab8a781\[38;5;4me884[39m]8;;\
+    │
+    ~
+    [EOF]
+    ");
+    let output = work_dir.run_jj([
+        "log",
+        "--color=never",
+        "--config=ui.log-word-wrap=true",
+        "-r@",
+        "-T",
+        template,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    @  e884
+    │
+    ~
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_templater_alias() {
     let test_env = TestEnvironment::default();
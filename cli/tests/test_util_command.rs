@@ -122,6 +122,39 @@ fn test_shell_completions() {
     test("zsh");
 }
 
+#[test]
+fn test_util_install_man_pages() {
+    let test_env = TestEnvironment::default();
+    let man_dir = test_env.env_root().join("man");
+    test_env
+        .run_jj_in(
+            ".",
+            ["util", "install-man-pages", man_dir.to_str().unwrap()],
+        )
+        .success();
+    let page = std::fs::read_to_string(man_dir.join("man1").join("jj-git-fetch.1")).unwrap();
+    let (_, see_also) = page.split_once(".SH \"SEE ALSO\"\n").unwrap();
+    insta::assert_snapshot!(see_also, @r"\fBjj\-git\-clone\fR(1), \fBjj\-git\-export\fR(1), \fBjj\-git\-import\fR(1), \fBjj\-git\-init\fR(1), \fBjj\-git\-push\fR(1), \fBjj\-git\-remote\fR(1), \fBjj\-git\-root\fR(1)");
+    // The top-level command has no siblings
+    let page = std::fs::read_to_string(man_dir.join("man1").join("jj.1")).unwrap();
+    assert!(!page.contains("SEE ALSO"));
+
+    let man_dir = test_env.env_root().join("man-no-see-also");
+    test_env
+        .run_jj_in(
+            ".",
+            [
+                "util",
+                "install-man-pages",
+                "--no-see-also",
+                man_dir.to_str().unwrap(),
+            ],
+        )
+        .success();
+    let page = std::fs::read_to_string(man_dir.join("man1").join("jj-git-fetch.1")).unwrap();
+    assert!(!page.contains("SEE ALSO"));
+}
+
 #[test]
 fn test_util_exec() {
     let test_env = TestEnvironment::default();
@@ -588,6 +588,11 @@ impl Index for CompositeIndex {
         self.commits().has_id(commit_id)
     }
 
+    fn generation_number(&self, commit_id: &CommitId) -> Option<u32> {
+        let entry = self.commits().entry_by_id(commit_id)?;
+        Some(entry.generation_number())
+    }
+
     fn is_ancestor(&self, ancestor_id: &CommitId, descendant_id: &CommitId) -> bool {
         self.commits().is_ancestor(ancestor_id, descendant_id)
     }
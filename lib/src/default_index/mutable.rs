@@ -523,6 +523,10 @@ impl Index for DefaultMutableIndex {
         self.0.has_id(commit_id)
     }
 
+    fn generation_number(&self, commit_id: &CommitId) -> Option<u32> {
+        self.0.generation_number(commit_id)
+    }
+
     fn is_ancestor(&self, ancestor_id: &CommitId, descendant_id: &CommitId) -> bool {
         self.0.is_ancestor(ancestor_id, descendant_id)
     }
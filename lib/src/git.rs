@@ -2417,4 +2417,19 @@ pub struct Progress {
     /// `Some` iff data transfer is currently in progress
     pub bytes_downloaded: Option<u64>,
     pub overall: f32,
+    /// Stage of the remote operation last reported by Git, if known
+    pub phase: Option<ProgressPhase>,
+}
+
+/// Stage of a remote operation as reported by Git.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgressPhase {
+    /// Remote is enumerating objects to send.
+    CountingObjects,
+    /// Remote is compressing objects to send.
+    CompressingObjects,
+    /// Objects are being transferred.
+    ReceivingObjects,
+    /// Received objects are being indexed locally.
+    ResolvingDeltas,
 }
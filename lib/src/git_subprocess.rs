@@ -30,6 +30,7 @@ use thiserror::Error;
 
 use crate::git::GitPushStats;
 use crate::git::Progress;
+use crate::git::ProgressPhase;
 use crate::git::RefSpec;
 use crate::git::RefToPush;
 use crate::git::RemoteCallbacks;
@@ -619,9 +620,39 @@ struct GitProgress {
     objects: (u64, u64),
     counted_objects: (u64, u64),
     compressed_objects: (u64, u64),
+    phase: Option<ProgressPhase>,
 }
 
 impl GitProgress {
+    /// Updates the progress if the line is a progress message. Returns true if
+    /// the line is consumed.
+    fn update(&mut self, line: &[u8]) -> bool {
+        let (counts, phase, rest) = if let Some(rest) = line.strip_prefix(b"Receiving objects:") {
+            (&mut self.objects, ProgressPhase::ReceivingObjects, rest)
+        } else if let Some(rest) = line.strip_prefix(b"Resolving deltas:") {
+            (&mut self.deltas, ProgressPhase::ResolvingDeltas, rest)
+        } else if let Some(rest) = line.strip_prefix(b"remote: Counting objects:") {
+            (
+                &mut self.counted_objects,
+                ProgressPhase::CountingObjects,
+                rest,
+            )
+        } else if let Some(rest) = line.strip_prefix(b"remote: Compressing objects:") {
+            (
+                &mut self.compressed_objects,
+                ProgressPhase::CompressingObjects,
+                rest,
+            )
+        } else {
+            return false;
+        };
+        if let Some((frac, total)) = read_progress_line(rest) {
+            *counts = (frac, total);
+        }
+        self.phase = Some(phase);
+        true
+    }
+
     fn to_progress(&self) -> Progress {
         Progress {
             bytes_downloaded: None,
@@ -630,6 +661,7 @@ impl GitProgress {
             } else {
                 0.0
             },
+            phase: self.phase,
         }
     }
 
@@ -659,19 +691,7 @@ fn read_to_end_with_progress<R: Read>(
             break;
         }
 
-        if update_progress(line, &mut git_progress.objects, b"Receiving objects:")
-            || update_progress(line, &mut git_progress.deltas, b"Resolving deltas:")
-            || update_progress(
-                line,
-                &mut git_progress.counted_objects,
-                b"remote: Counting objects:",
-            )
-            || update_progress(
-                line,
-                &mut git_progress.compressed_objects,
-                b"remote: Compressing objects:",
-            )
-        {
+        if git_progress.update(line) {
             if let Some(cb) = callbacks.progress.as_mut() {
                 cb(&git_progress.to_progress());
             }
@@ -690,18 +710,6 @@ fn read_to_end_with_progress<R: Read>(
     Ok(data)
 }
 
-fn update_progress(line: &[u8], progress: &mut (u64, u64), prefix: &[u8]) -> bool {
-    if let Some(line) = line.strip_prefix(prefix) {
-        if let Some((frac, total)) = read_progress_line(line) {
-            *progress = (frac, total);
-        }
-
-        true
-    } else {
-        false
-    }
-}
-
 fn read_until_cr_or_lf<R: io::BufRead + ?Sized>(
     reader: &mut R,
     dest_buf: &mut Vec<u8>,
@@ -932,6 +940,9 @@ Done";
             Progress {
                 bytes_downloaded: None,
                 overall: 0.5,
+                phase: Some(
+                    ResolvingDeltas,
+                ),
             },
         ]
         ");
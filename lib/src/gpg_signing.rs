@@ -25,6 +25,8 @@ use std::str;
 
 use thiserror::Error;
 
+use crate::backend::MillisSinceEpoch;
+use crate::backend::Timestamp;
 use crate::config::ConfigGetError;
 use crate::settings::UserSettings;
 use crate::signing::SigStatus;
@@ -39,13 +41,19 @@ use crate::signing::Verification;
 //  [GNUPG:] BADSIG <long keyid> <primary uid..>
 // in the output from --status-fd=1
 // Assume signature is invalid if none of the above was found
+//
+// The fingerprint and creation time are taken from the optional
+//  [GNUPG:] VALIDSIG <fingerprint> <sig creation date> <sig timestamp> ..
 fn parse_gpg_verify_output(
     output: &[u8],
     allow_expired_keys: bool,
 ) -> Result<Verification, SignError> {
-    output
-        .split(|&b| b == b'\n')
-        .filter_map(|line| line.strip_prefix(b"[GNUPG:] "))
+    let status_lines = || {
+        output
+            .split(|&b| b == b'\n')
+            .filter_map(|line| line.strip_prefix(b"[GNUPG:] "))
+    };
+    let mut verification = status_lines()
         .find_map(|line| {
             let mut parts = line.splitn(3, |&b| b == b' ').fuse();
             let status = match parts.next()? {
@@ -75,7 +83,39 @@ fn parse_gpg_verify_output(
                 .map(|value| value.trim().to_owned());
             Some(Verification::new(status, key, display))
         })
-        .ok_or(SignError::InvalidSignatureFormat)
+        .ok_or(SignError::InvalidSignatureFormat)?;
+    if let Some((fingerprint, created)) = status_lines().find_map(parse_gpg_validsig) {
+        verification.fingerprint = Some(fingerprint);
+        verification.created = created;
+    }
+    Ok(verification)
+}
+
+/// Parses the fingerprint and the signature creation time from the
+/// `VALIDSIG` status line.
+fn parse_gpg_validsig(line: &[u8]) -> Option<(String, Option<Timestamp>)> {
+    let line = str::from_utf8(line.strip_prefix(b"VALIDSIG ")?).ok()?;
+    let mut parts = line.split(' ');
+    let fingerprint = parts.next().filter(|s| !s.is_empty())?.to_owned();
+    let created = parts.nth(1).and_then(parse_gpg_timestamp);
+    Some((fingerprint, created))
+}
+
+/// Parses a status timestamp, which is either seconds since the epoch (gpg)
+/// or an ISO 8601 string such as `20250320T132510` (gpgsm).
+fn parse_gpg_timestamp(value: &str) -> Option<Timestamp> {
+    let secs = if value.contains('T') {
+        chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .ok()?
+            .and_utc()
+            .timestamp()
+    } else {
+        value.parse().ok()?
+    };
+    Some(Timestamp {
+        timestamp: MillisSinceEpoch(secs.checked_mul(1000)?),
+        tz_offset: 0,
+    })
 }
 
 fn run_sign_command(command: &mut Command, input: &[u8]) -> Result<Vec<u8>, GpgError> {
@@ -353,6 +393,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn gpg_verify_valid_signature_details() {
+        let output = b"\
+[GNUPG:] NEWSIG someone@example.com
+[GNUPG:] GOODSIG 4ED556E9729E000F Someone
+[GNUPG:] VALIDSIG 475040684A54DD3294894BC54ED556E9729E000F 2023-11-26 1701042000 0 4 0 22 8 00 475040684A54DD3294894BC54ED556E9729E000F
+[GNUPG:] TRUST_UNDEFINED 0 pgp
+";
+        let verification = parse_gpg_verify_output(output, true).unwrap();
+        assert_eq!(verification.status, SigStatus::Good);
+        assert_eq!(verification.key.as_deref(), Some("4ED556E9729E000F"));
+        assert_eq!(
+            verification.fingerprint.as_deref(),
+            Some("475040684A54DD3294894BC54ED556E9729E000F")
+        );
+        assert_eq!(
+            verification.created,
+            Some(Timestamp {
+                timestamp: MillisSinceEpoch(1701042000000),
+                tz_offset: 0,
+            })
+        );
+
+        // gpgsm reports ISO 8601 timestamps
+        let output = b"\
+[GNUPG:] GOODSIG 4C625C10FF7180164F19C6571D513E4E0BEA555C /CN=JJ Cert
+[GNUPG:] VALIDSIG 4C625C10FF7180164F19C6571D513E4E0BEA555C 20250320 20250320T132510 00000000T000000 0 0 1 8 00
+";
+        let verification = parse_gpg_verify_output(output, true).unwrap();
+        assert_eq!(
+            verification.created,
+            Some(Timestamp {
+                timestamp: MillisSinceEpoch(1742477110000),
+                tz_offset: 0,
+            })
+        );
+
+        // Unparsable timestamp
+        let output = b"\
+[GNUPG:] GOODSIG 123 456
+[GNUPG:] VALIDSIG ABCDEF 2023-11-26 bogus
+";
+        let verification = parse_gpg_verify_output(output, true).unwrap();
+        assert_eq!(verification.fingerprint.as_deref(), Some("ABCDEF"));
+        assert_eq!(verification.created, None);
+    }
+
     #[test]
     fn gpgsm_verify_unknown_signature() {
         assert_eq!(
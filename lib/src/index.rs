@@ -98,6 +98,11 @@ pub trait Index: Send + Sync {
     /// Returns true if `commit_id` is present in the index.
     fn has_id(&self, commit_id: &CommitId) -> bool;
 
+    /// Returns the generation number of the `commit_id`, which is the length
+    /// of the longest path to the root commit. The root commit is at
+    /// generation 0. Returns `None` if the commit isn't present in the index.
+    fn generation_number(&self, commit_id: &CommitId) -> Option<u32>;
+
     /// Returns true if `ancestor_id` commit is an ancestor of the
     /// `descendant_id` commit, or if `ancestor_id` equals `descendant_id`.
     fn is_ancestor(&self, ancestor_id: &CommitId, descendant_id: &CommitId) -> bool;
@@ -23,6 +23,7 @@ use clru::CLruCache;
 use thiserror::Error;
 
 use crate::backend::CommitId;
+use crate::backend::Timestamp;
 use crate::config::ConfigGetError;
 use crate::gpg_signing::GpgBackend;
 use crate::gpg_signing::GpgsmBackend;
@@ -55,8 +56,9 @@ impl Display for SigStatus {
 }
 
 /// The result of a signature verification.
-/// Key and display are optional additional info that backends can or can not
-/// provide to add additional information for the templater to potentially show.
+/// Key, display, fingerprint, and creation time are optional additional info
+/// that backends can or can not provide to add additional information for the
+/// templater to potentially show.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Verification {
     /// The status of the signature.
@@ -67,6 +69,10 @@ pub struct Verification {
     /// A display string, if available. For GPG, this will be formatted primary
     /// user ID.
     pub display: Option<String>,
+    /// The full fingerprint of the signing key, if available.
+    pub fingerprint: Option<String>,
+    /// The time when the signature was created, if available.
+    pub created: Option<Timestamp>,
 }
 
 impl Verification {
@@ -77,6 +83,8 @@ impl Verification {
             status: SigStatus::Unknown,
             key: None,
             display: None,
+            fingerprint: None,
+            created: None,
         }
     }
 
@@ -86,6 +94,8 @@ impl Verification {
             status,
             key,
             display,
+            fingerprint: None,
+            created: None,
         }
     }
 }
@@ -68,6 +68,20 @@ fn parse_utf8_string(data: Vec<u8>) -> SshResult<String> {
     String::from_utf8(data).map_err(|_| SshError::BadResult)
 }
 
+// Extracts the key fingerprint from the ssh-keygen verification output:
+//  Good "git" signature [for <principal>] with <key type> key <fingerprint>
+// SSH signatures don't record the creation time, so there's nothing else to
+// extract.
+fn parse_verify_fingerprint(output: &[u8]) -> Option<String> {
+    let output = std::str::from_utf8(output).ok()?;
+    output
+        .lines()
+        .filter(|line| line.starts_with("Good "))
+        .find_map(|line| line.rsplit_once(" key "))
+        .map(|(_, fingerprint)| fingerprint.trim().to_owned())
+        .filter(|fingerprint| !fingerprint.is_empty())
+}
+
 fn run_command(command: &mut Command, stdin: &[u8]) -> SshResult<Vec<u8>> {
     tracing::info!(?command, "running SSH signing command");
     let process = command.spawn()?;
@@ -272,11 +286,13 @@ impl SigningBackend for SshBackend {
 
                 let result = run_command(&mut command, data);
 
-                let status = match result {
-                    Ok(_) => SigStatus::Good,
-                    Err(_) => SigStatus::Bad,
+                let (status, fingerprint) = match result {
+                    Ok(output) => (SigStatus::Good, parse_verify_fingerprint(&output)),
+                    Err(_) => (SigStatus::Bad, None),
                 };
-                Ok(Verification::new(status, None, Some(principal)))
+                let mut verification = Verification::new(status, None, Some(principal));
+                verification.fingerprint = fingerprint;
+                Ok(verification)
             }
             _ => {
                 command
@@ -290,11 +306,15 @@ impl SigningBackend for SshBackend {
                 let result = run_command(&mut command, data);
 
                 match result {
-                    Ok(_) => Ok(Verification::new(
-                        SigStatus::Unknown,
-                        None,
-                        Some("Signature OK. Unknown principal".into()),
-                    )),
+                    Ok(output) => {
+                        let mut verification = Verification::new(
+                            SigStatus::Unknown,
+                            None,
+                            Some("Signature OK. Unknown principal".into()),
+                        );
+                        verification.fingerprint = parse_verify_fingerprint(&output);
+                        Ok(verification)
+                    }
                     Err(_) => Ok(Verification::new(SigStatus::Bad, None, None)),
                 }
             }
@@ -309,6 +329,25 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_verify_fingerprint() {
+        assert_eq!(
+            parse_verify_fingerprint(
+                b"Good \"git\" signature for me with ED25519 key SHA256:5/uH5U2cZMWhzd/+bLqL\n"
+            ),
+            Some("SHA256:5/uH5U2cZMWhzd/+bLqL".to_owned())
+        );
+        assert_eq!(
+            parse_verify_fingerprint(b"Good \"git\" signature with RSA key SHA256:abc\n"),
+            Some("SHA256:abc".to_owned())
+        );
+        assert_eq!(parse_verify_fingerprint(b""), None);
+        assert_eq!(
+            parse_verify_fingerprint(b"Could not verify signature.\n"),
+            None
+        );
+    }
+
     #[test]
     fn test_ssh_key_to_file_conversion_raw_key_data() {
         let keydata = "ssh-ed25519 some-key-data";
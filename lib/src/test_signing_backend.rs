@@ -57,6 +57,10 @@ impl SigningBackend for TestSigningBackend {
             return Err(SignError::InvalidSignatureFormat);
         };
         let key = (!key.is_empty()).then_some(std::str::from_utf8(key).unwrap().to_owned());
+        // There's no real key, so derive a stable fingerprint from the key name.
+        let fingerprint = key
+            .as_ref()
+            .map(|key| hex_util::encode_hex(&blake2b_hash(key)[..20]));
 
         let sig = self.sign(data, key.as_deref())?;
         if sig == signature {
@@ -64,12 +68,16 @@ impl SigningBackend for TestSigningBackend {
                 status: SigStatus::Good,
                 key,
                 display: Some("test-display".into()),
+                fingerprint,
+                created: None,
             })
         } else {
             Ok(Verification {
                 status: SigStatus::Bad,
                 key,
                 display: Some("test-display".into()),
+                fingerprint,
+                created: None,
             })
         }
     }
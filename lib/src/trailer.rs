@@ -73,6 +73,35 @@ pub fn parse_description_trailers(body: &str) -> Vec<Trailer> {
     }
 }
 
+/// Returns the canonical spelling of the trailer `key`.
+///
+/// Well-known keys such as `Signed-off-by` and `Change-Id` are spelled as
+/// conventionally used by Git and Gerrit. Other keys are capitalized, and the
+/// remaining letters are converted to lowercase.
+pub fn normalize_key(key: &str) -> String {
+    const KNOWN_KEYS: &[&str] = &[
+        "Acked-by",
+        "Change-Id",
+        "Co-authored-by",
+        "Helped-by",
+        "Reported-by",
+        "Reviewed-by",
+        "Signed-off-by",
+        "Suggested-by",
+        "Tested-by",
+    ];
+    if let Some(known) = KNOWN_KEYS.iter().find(|k| k.eq_ignore_ascii_case(key)) {
+        return (*known).to_owned();
+    }
+    let mut chars = key.chars();
+    chars
+        .next()
+        .map(|c| c.to_ascii_uppercase())
+        .into_iter()
+        .chain(chars.map(|c| c.to_ascii_lowercase()))
+        .collect()
+}
+
 /// Parse the trailers from a trailer paragraph. This function behaves like
 /// `parse_description_trailer`, but will return an error if a blank or
 /// non trailer line is found.
@@ -143,6 +172,18 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_normalize_key() {
+        assert_eq!(normalize_key("signed-off-by"), "Signed-off-by");
+        assert_eq!(normalize_key("SIGNED-OFF-BY"), "Signed-off-by");
+        assert_eq!(normalize_key("change-id"), "Change-Id");
+        assert_eq!(normalize_key("co-Authored-By"), "Co-authored-by");
+        assert_eq!(normalize_key("BUG"), "Bug");
+        assert_eq!(normalize_key("fixes"), "Fixes");
+        assert_eq!(normalize_key("X-Custom-Key"), "X-custom-key");
+        assert_eq!(normalize_key(""), "");
+    }
+
     #[test]
     fn test_simple_trailers() {
         let descriptions = indoc! {r#"
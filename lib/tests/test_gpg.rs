@@ -220,6 +220,10 @@ fn gpg_signing_roundtrip() {
         check.display.unwrap(),
         "Someone (jj test signing key) <someone@example.com>"
     );
+    assert_eq!(
+        check.fingerprint.unwrap(),
+        "A58E5EE9067153C8F5E5E9E9638785CB16FEA061"
+    );
 
     let check = backend.verify(b"so so bad", &signature).unwrap();
     assert_eq!(check.status, SigStatus::Bad);
@@ -249,6 +253,17 @@ fn gpg_signing_roundtrip_explicit_key() {
         display: Some(
             "Someone Else (jj test signing key) <someone-else@example.com>",
         ),
+        fingerprint: Some(
+            "475040684A54DD3294894BC54ED556E9729E000F",
+        ),
+        created: Some(
+            Timestamp {
+                timestamp: MillisSinceEpoch(
+                    1701042000000,
+                ),
+                tz_offset: 0,
+            },
+        ),
     }
     "#);
     assert_debug_snapshot!(backend.verify(b"so so bad", &signature).unwrap(), @r#"
@@ -260,6 +275,8 @@ fn gpg_signing_roundtrip_explicit_key() {
         display: Some(
             "Someone Else (jj test signing key) <someone-else@example.com>",
         ),
+        fingerprint: None,
+        created: None,
     }
     "#);
 }
@@ -285,6 +302,8 @@ fn gpg_unknown_key() {
             "071FE3E324DD7333",
         ),
         display: None,
+        fingerprint: None,
+        created: None,
     }
     "#);
     assert_debug_snapshot!(backend.verify(b"so bad", signature).unwrap(), @r#"
@@ -294,6 +313,8 @@ fn gpg_unknown_key() {
             "071FE3E324DD7333",
         ),
         display: None,
+        fingerprint: None,
+        created: None,
     }
     "#);
 }
@@ -370,6 +391,17 @@ fn gpgsm_signing_roundtrip_explicit_key() {
         display: Some(
             "/CN=JJ Cert/O=GPGSM Signing Test/EMail=someone@example.com",
         ),
+        fingerprint: Some(
+            "4C625C10FF7180164F19C6571D513E4E0BEA555C",
+        ),
+        created: Some(
+            Timestamp {
+                timestamp: MillisSinceEpoch(
+                    1742477110000,
+                ),
+                tz_offset: 0,
+            },
+        ),
     }
     "#);
     assert_debug_snapshot!(backend.verify(b"so so bad", &signature).unwrap(), @r#"
@@ -381,6 +413,8 @@ fn gpgsm_signing_roundtrip_explicit_key() {
         display: Some(
             "/CN=JJ Cert/O=GPGSM Signing Test/EMail=someone@example.com",
         ),
+        fingerprint: None,
+        created: None,
     }
     "#);
 }
@@ -415,6 +449,8 @@ fn gpgsm_unknown_key() {
         status: Unknown,
         key: None,
         display: None,
+        fingerprint: None,
+        created: None,
     }
     "#);
     assert_debug_snapshot!(backend.verify(b"so bad", signature).unwrap(), @r#"
@@ -422,6 +458,8 @@ fn gpgsm_unknown_key() {
         status: Unknown,
         key: None,
         display: None,
+        fingerprint: None,
+        created: None,
     }
     "#);
 }
@@ -140,6 +140,42 @@ fn test_index_commits_standard_cases() {
     assert!(index.is_ancestor(commit_a.id(), commit_h.id()));
 }
 
+#[test]
+fn test_index_generation_number() {
+    let test_repo = TestRepo::init();
+    let repo = &test_repo.repo;
+
+    // o C
+    // |\
+    // o | B
+    // |/
+    // o A
+    // o root
+    let root_commit_id = repo.store().root_commit_id();
+    let mut tx = repo.start_transaction();
+    let commit_a = write_random_commit(tx.repo_mut());
+    let commit_b = write_random_commit_with_parents(tx.repo_mut(), &[&commit_a]);
+    let commit_c = write_random_commit_with_parents(tx.repo_mut(), &[&commit_a, &commit_b]);
+    let missing_id = CommitId::from_hex("ffffff");
+
+    // Mutable index
+    let index = tx.repo().index();
+    assert_eq!(index.generation_number(root_commit_id), Some(0));
+    assert_eq!(index.generation_number(commit_a.id()), Some(1));
+    assert_eq!(index.generation_number(commit_b.id()), Some(2));
+    assert_eq!(index.generation_number(commit_c.id()), Some(3));
+    assert_eq!(index.generation_number(&missing_id), None);
+
+    // Readonly index
+    let repo = tx.commit("test").unwrap();
+    let index = repo.index();
+    assert_eq!(index.generation_number(root_commit_id), Some(0));
+    assert_eq!(index.generation_number(commit_a.id()), Some(1));
+    assert_eq!(index.generation_number(commit_b.id()), Some(2));
+    assert_eq!(index.generation_number(commit_c.id()), Some(3));
+    assert_eq!(index.generation_number(&missing_id), None);
+}
+
 #[test]
 fn test_index_commits_criss_cross() {
     let test_repo = TestRepo::init();
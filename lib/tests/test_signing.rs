@@ -55,6 +55,8 @@ fn good_verification() -> Option<Verification> {
         status: SigStatus::Good,
         key: Some("impeccable".to_owned()),
         display: Some("test-display".into()),
+        fingerprint: Some("24099127aeaefea801091abb21b0f073aa716c1e".to_owned()),
+        created: None,
     })
 }
 
@@ -147,6 +147,10 @@ fn ssh_signing_roundtrip() {
     assert_eq!(check.status, SigStatus::Good);
 
     assert_eq!(check.display.unwrap(), "test@example.com");
+    assert_eq!(
+        check.fingerprint.unwrap(),
+        "SHA256:CaeelDOMvTqGZPjAS9fdbnACrLg68N1Bb9ux5y6GjGw"
+    );
 
     let check = backend.verify(b"invalid-commit-data", &signature).unwrap();
     assert_eq!(check.status, SigStatus::Bad);